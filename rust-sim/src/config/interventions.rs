@@ -0,0 +1,58 @@
+use crate::config::parameters::TierConfig;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A discrete change applied by the engine at the start of timestep `at`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Intervention {
+    pub at: usize,
+    #[serde(flatten)]
+    pub action: InterventionAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum InterventionAction {
+    /// Replace a config value by dotted path, e.g. `policy.mitigation_strength`.
+    /// The value may be a number or a schedule table. Fields only read at
+    /// start-up (`time_steps`, `initial_agents`, `random_seed`) have no effect.
+    SetParameter { field: String, value: Value },
+    /// Deorbit a fraction of the active satellites in one tier, or in all
+    /// tiers if `tier` is omitted. Objects that cannot maneuver are left alone.
+    ForceDeorbit {
+        #[serde(default)]
+        tier: Option<String>,
+        #[schemars(range(min = 0.0, max = 1.0))]
+        fraction: f64,
+        #[serde(default)]
+        non_compliant_only: bool,
+    },
+    /// Open a new capacity tier; launches start targeting it immediately.
+    AddTier { tier: TierConfig },
+}
+
+impl InterventionAction {
+    pub fn describe(&self) -> String {
+        match self {
+            InterventionAction::SetParameter { field, value } => {
+                format!("set {} = {}", field, value)
+            }
+            InterventionAction::ForceDeorbit {
+                tier,
+                fraction,
+                non_compliant_only,
+            } => format!(
+                "force-deorbit {:.0}% of {}agents in {}",
+                fraction * 100.0,
+                if *non_compliant_only {
+                    "non-compliant "
+                } else {
+                    ""
+                },
+                tier.as_deref().unwrap_or("all tiers")
+            ),
+            InterventionAction::AddTier { tier } => format!("add tier {}", tier.name),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::config::loader::load_file;
+use crate::config::parameters::SimulationConfig;
+use serde_json::Value;
+use std::error::Error;
+use std::path::Path;
+
+/// Apply `patch` onto `target` with JSON merge-patch semantics (RFC 7396):
+/// objects are merged key by key, `null` removes a key, and anything else,
+/// lists included, replaces the target value.
+pub fn merge_patch(target: &mut Value, patch: Value) {
+    match patch {
+        Value::Object(entries) => {
+            if !target.is_object() {
+                *target = Value::Object(Default::default());
+            }
+            let object = target.as_object_mut().expect("target is an object");
+            for (key, value) in entries {
+                if value.is_null() {
+                    object.remove(&key);
+                } else {
+                    merge_patch(object.entry(key).or_insert(Value::Null), value);
+                }
+            }
+        }
+        other => *target = other,
+    }
+}
+
+/// Parse a `path=value` assignment such as `policy.launch_rate=0.05`. The value
+/// is read as JSON when possible, so numbers, booleans and schedule tables keep
+/// their type; anything else is taken as a plain string.
+pub fn parse_assignment(assignment: &str) -> Result<(String, Value), Box<dyn Error>> {
+    let (path, raw) = assignment
+        .split_once('=')
+        .ok_or_else(|| format!("expected PATH=VALUE, got '{}'", assignment))?;
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(format!("missing config path in '{}'", assignment).into());
+    }
+    let raw = raw.trim();
+    let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()));
+    Ok((path.to_string(), value))
+}
+
+impl SimulationConfig {
+    /// Merge a partial config on top of this one.
+    pub fn with_overlay(self, overlay: Value) -> Result<Self, Box<dyn Error>> {
+        let mut tree = serde_json::to_value(&self)?;
+        merge_patch(&mut tree, overlay);
+        Ok(serde_json::from_value(tree)?)
+    }
+
+    /// Merge a partial scenario file (.toml or .json) on top of this config.
+    pub fn with_overlay_file<P: AsRef<Path>>(self, path: P) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let overlay: Value = load_file(path)?;
+        self.with_overlay(overlay)
+            .map_err(|e| format!("overlay {}: {}", path.display(), e).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_patch() {
+        let mut target = json!({"a": {"b": 1, "c": 2}, "list": [1, 2], "seed": 42});
+        merge_patch(
+            &mut target,
+            json!({"a": {"c": 3}, "list": [9], "seed": null}),
+        );
+        assert_eq!(target, json!({"a": {"b": 1, "c": 3}, "list": [9]}));
+    }
+
+    #[test]
+    fn test_overlay_keeps_unmentioned_fields() {
+        let config = SimulationConfig::default()
+            .with_overlay(json!({"policy": {"launch_rate": 0.05}, "random_seed": null}))
+            .unwrap();
+        assert_eq!(config.policy.launch_rate, 0.05.into());
+        assert_eq!(config.policy.compliance_rate, 0.85.into());
+        assert_eq!(config.random_seed, None);
+        assert_eq!(config.capacity_tiers.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("capacity_tiers[1].max_capacity=400").unwrap(),
+            ("capacity_tiers[1].max_capacity".to_string(), json!(400))
+        );
+        assert_eq!(
+            parse_assignment("capacity_tiers[0].name = LEO-Very-Low")
+                .unwrap()
+                .1,
+            json!("LEO-Very-Low")
+        );
+        assert!(parse_assignment("policy.launch_rate").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::config::parameters::SimulationConfig;
+use serde::de::DeserializeOwned;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "toml" => Some(ConfigFormat::Toml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Read any TOML or JSON spec file, picking the format from the file extension.
+pub fn load_file<T: DeserializeOwned>(path: &Path) -> Result<T, Box<dyn Error>> {
+    let format = ConfigFormat::from_path(path).ok_or_else(|| {
+        format!(
+            "unsupported config format for {} (expected .toml or .json)",
+            path.display()
+        )
+    })?;
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+    parse_str(&contents, format)
+        .map_err(|e| format!("failed to parse {}: {}", path.display(), e).into())
+}
+
+pub fn parse_str<T: DeserializeOwned>(
+    contents: &str,
+    format: ConfigFormat,
+) -> Result<T, Box<dyn Error>> {
+    let value = match format {
+        ConfigFormat::Toml => toml::from_str(contents)?,
+        ConfigFormat::Json => serde_json::from_str(contents)?,
+    };
+    Ok(value)
+}
+
+impl SimulationConfig {
+    /// Load a scenario file, picking the format from the file extension.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        load_file(path.as_ref())
+    }
+
+    pub fn from_str_with_format(
+        contents: &str,
+        format: ConfigFormat,
+    ) -> Result<Self, Box<dyn Error>> {
+        parse_str(contents, format)
+    }
+
+    pub fn to_string_with_format(&self, format: ConfigFormat) -> Result<String, Box<dyn Error>> {
+        let contents = match format {
+            ConfigFormat::Toml => toml::to_string_pretty(self)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parameters::TierConfig;
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("a.toml")),
+            Some(ConfigFormat::Toml)
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("a.JSON")),
+            Some(ConfigFormat::Json)
+        );
+        assert_eq!(ConfigFormat::from_path(Path::new("a.yaml")), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = SimulationConfig::default();
+        for format in [ConfigFormat::Toml, ConfigFormat::Json] {
+            let text = config.to_string_with_format(format).unwrap();
+            let parsed = SimulationConfig::from_str_with_format(&text, format).unwrap();
+            assert_eq!(parsed.time_steps, config.time_steps);
+            assert_eq!(parsed.capacity_tiers.len(), config.capacity_tiers.len());
+            assert_eq!(parsed.policy.launch_rate, config.policy.launch_rate);
+        }
+    }
+
+    #[test]
+    fn test_loads_config_without_altitude_bands() {
+        // scenarios/default.toml as it was before tiers had altitude bands
+        let text = r#"
+            time_steps = 1000
+            initial_agents = 500
+            random_seed = 42
+
+            [[capacity_tiers]]
+            name = "LEO-Low"
+            max_capacity = 200
+            congestion_threshold = 0.7
+            failure_base_rate = 0.001
+
+            [[capacity_tiers]]
+            name = "LEO-Mid"
+            max_capacity = 300
+            congestion_threshold = 0.75
+            failure_base_rate = 0.0008
+
+            [[capacity_tiers]]
+            name = "LEO-High"
+            max_capacity = 250
+            congestion_threshold = 0.8
+            failure_base_rate = 0.0006
+
+            [policy]
+            launch_rate = 0.02
+            mitigation_strength = 0.3
+            compliance_rate = 0.85
+            deorbit_threshold = 0.9
+
+            [reporting]
+            report_interval = 10
+            hedging_enabled = true
+            confidence_modulation = true
+            ambiguity_level = 0.5
+        "#;
+        let config = SimulationConfig::from_str_with_format(text, ConfigFormat::Toml).unwrap();
+        config.validate().unwrap();
+
+        let defaults = SimulationConfig::default();
+        for (i, (tier, default)) in config
+            .capacity_tiers
+            .iter()
+            .zip(&defaults.capacity_tiers)
+            .enumerate()
+        {
+            assert_eq!(tier.band(i), default.band(i));
+        }
+        let added = TierConfig {
+            min_altitude_km: None,
+            max_altitude_km: None,
+            ..defaults.capacity_tiers[0].clone()
+        };
+        assert_eq!(added.band(3), (1200.0, 1600.0));
+    }
+}
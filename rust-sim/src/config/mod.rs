@@ -1 +1,9 @@
-pub mod parameters;
\ No newline at end of file
+pub mod interventions;
+pub mod layering;
+pub mod loader;
+pub mod parameters;
+pub mod path;
+pub mod scenarios;
+pub mod schedule;
+pub mod schema;
+pub mod validation;
@@ -1,89 +1,619 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SimulationConfig {
-    pub time_steps: usize,
-    pub initial_agents: usize,
-    pub capacity_tiers: Vec<TierConfig>,
-    pub policy: PolicyConfig,
-    pub reporting: ReportingConfig,
-    pub random_seed: Option<u64>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TierConfig {
-    pub name: String,
-    pub max_capacity: usize,
-    pub congestion_threshold: f64,
-    pub failure_base_rate: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PolicyConfig {
-    pub launch_rate: f64,
-    pub mitigation_strength: f64,
-    pub compliance_rate: f64,
-    pub deorbit_threshold: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReportingConfig {
-    pub report_interval: usize,
-    pub hedging_enabled: bool,
-    pub confidence_modulation: bool,
-    pub ambiguity_level: f64,
-}
-
-impl Default for SimulationConfig {
-    fn default() -> Self {
-        Self {
-            time_steps: 1000,
-            initial_agents: 500,
-            capacity_tiers: vec![
-                TierConfig {
-                    name: "LEO-Low".to_string(),
-                    max_capacity: 200,
-                    congestion_threshold: 0.7,
-                    failure_base_rate: 0.001,
-                },
-                TierConfig {
-                    name: "LEO-Mid".to_string(),
-                    max_capacity: 300,
-                    congestion_threshold: 0.75,
-                    failure_base_rate: 0.0008,
-                },
-                TierConfig {
-                    name: "LEO-High".to_string(),
-                    max_capacity: 250,
-                    congestion_threshold: 0.8,
-                    failure_base_rate: 0.0006,
-                },
-            ],
-            policy: PolicyConfig {
-                launch_rate: 0.02,
-                mitigation_strength: 0.3,
-                compliance_rate: 0.85,
-                deorbit_threshold: 0.9,
-            },
-            reporting: ReportingConfig {
-                report_interval: 10,
-                hedging_enabled: true,
-                confidence_modulation: true,
-                ambiguity_level: 0.5,
-            },
-            random_seed: Some(42),
-        }
-    }
-}
-
-impl SimulationConfig {
-    pub fn with_policy(mut self, policy: PolicyConfig) -> Self {
-        self.policy = policy;
-        self
-    }
-
-    pub fn with_time_steps(mut self, steps: usize) -> Self {
-        self.time_steps = steps;
-        self
-    }
-}
\ No newline at end of file
+use crate::config::interventions::Intervention;
+use crate::config::schedule::Schedule;
+use crate::config::schema;
+use crate::models::failure_rate::FailureCurve;
+use crate::models::object_kind::ObjectKind;
+use crate::models::subsystem::Subsystem;
+use crate::utils::rng::SimulationRng;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A complete simulation scenario.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SimulationConfig {
+    /// Number of timesteps to simulate.
+    pub time_steps: usize,
+    /// Simulated days per timestep; sets the pace of orbital decay and the
+    /// solar cycle.
+    #[serde(default = "default_days_per_step")]
+    #[schemars(range(min = 0.0))]
+    pub days_per_step: f64,
+    /// Objects placed round-robin across the tiers before the first step.
+    pub initial_agents: usize,
+    /// Capacity tiers (orbital shells); at least one is required.
+    #[schemars(length(min = 1))]
+    pub capacity_tiers: Vec<TierConfig>,
+    pub policy: PolicyConfig,
+    /// Organisations that own objects and launch new ones. When empty, a
+    /// single unaffiliated operator follows the policy-wide rates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operators: Vec<OperatorConfig>,
+    pub reporting: ReportingConfig,
+    /// Distributions physical properties of new agents are drawn from.
+    #[serde(default)]
+    pub agent_properties: AgentPropertiesConfig,
+    /// Make-up of the initial population and launch by-products.
+    #[serde(default)]
+    pub object_mix: ObjectMixConfig,
+    /// Debris generation when objects collide or fail.
+    #[serde(default)]
+    pub fragmentation: FragmentationConfig,
+    /// Atmospheric drag and natural re-entry.
+    #[serde(default)]
+    pub drag: DragConfig,
+    /// Random failures of satellite subsystems.
+    #[serde(default)]
+    pub subsystems: SubsystemConfig,
+    /// How long satellites take to commission, serve and deorbit.
+    #[serde(default)]
+    pub lifecycle: LifecycleConfig,
+    /// Screening for close approaches and collision avoidance.
+    #[serde(default)]
+    pub conjunctions: ConjunctionConfig,
+    /// How cascades spread between tiers.
+    #[serde(default)]
+    pub spillover: SpilloverConfig,
+    /// Seed for reproducible runs; omit to seed from entropy.
+    pub random_seed: Option<u64>,
+    /// Discrete changes applied at given timesteps.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interventions: Vec<Intervention>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TierConfig {
+    pub name: String,
+    /// Lower edge of the altitude shell, in km. Shells must not overlap.
+    /// If omitted, taken from the built-in shells by list position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0.0))]
+    pub min_altitude_km: Option<f64>,
+    /// Upper edge of the altitude shell, in km. If omitted, taken from the
+    /// built-in shells by list position.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_altitude_km: Option<f64>,
+    /// Number of agents the tier can hold before utilization reaches 1.
+    #[schemars(range(min = 1))]
+    pub max_capacity: usize,
+    /// Utilization above which the tier counts as congested, in (0, 1).
+    #[schemars(schema_with = "schema::open_unit_schedule")]
+    pub congestion_threshold: Schedule,
+    /// Per-agent, per-step collision probability below the congestion threshold.
+    #[schemars(schema_with = "schema::probability_schedule")]
+    pub failure_base_rate: Schedule,
+    /// How the collision probability grows with utilization.
+    #[serde(default)]
+    pub failure_curve: FailureCurve,
+    /// How debris in the tier reduces the capacity left for other objects.
+    #[serde(default)]
+    pub debris_degradation: DebrisDegradation,
+}
+
+/// Shell edges, in km, of the built-in tiers. Tiers listed beyond them get
+/// 400 km shells further up.
+const DEFAULT_SHELL_EDGES_KM: [f64; 4] = [300.0, 550.0, 800.0, 1200.0];
+
+fn default_shell_edge(index: usize) -> f64 {
+    let last = DEFAULT_SHELL_EDGES_KM.len() - 1;
+    match DEFAULT_SHELL_EDGES_KM.get(index) {
+        Some(&edge) => edge,
+        None => DEFAULT_SHELL_EDGES_KM[last] + 400.0 * (index - last) as f64,
+    }
+}
+
+impl TierConfig {
+    /// Altitude band of the tier at `position` in the tier list. Configs
+    /// written before tiers had bands get the built-in shells in list order.
+    pub fn band(&self, position: usize) -> (f64, f64) {
+        (
+            self.min_altitude_km
+                .unwrap_or_else(|| default_shell_edge(position)),
+            self.max_altitude_km
+                .unwrap_or_else(|| default_shell_edge(position + 1)),
+        )
+    }
+}
+
+/// Each tracked fragment stands for a cloud of untracked debris that makes
+/// part of the shell unsafe, so a tier's effective capacity shrinks as
+/// fragments accumulate and recovers as they decay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DebrisDegradation {
+    /// Capacity lost per debris fragment in the tier, on top of the slot
+    /// the fragment itself takes up.
+    #[schemars(range(min = 0.0))]
+    pub capacity_per_fragment: f64,
+    /// Share of the nominal capacity that remains however much debris
+    /// builds up, in (0, 1].
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub min_capacity_fraction: f64,
+}
+
+impl Default for DebrisDegradation {
+    fn default() -> Self {
+        Self {
+            capacity_per_fragment: 0.5,
+            min_capacity_fraction: 0.25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyConfig {
+    /// Probability of one new launch per step.
+    #[schemars(schema_with = "schema::probability_schedule")]
+    pub launch_rate: Schedule,
+    /// Strength of health-restoring mitigation for compliant agents, in [0, 1].
+    #[schemars(schema_with = "schema::probability_schedule")]
+    pub mitigation_strength: Schedule,
+    /// Probability that a new agent follows mitigation policy.
+    #[schemars(schema_with = "schema::probability_schedule")]
+    pub compliance_rate: Schedule,
+    /// Tier utilization above which agents are force-deorbited.
+    #[schemars(schema_with = "schema::positive_schedule")]
+    pub deorbit_threshold: Schedule,
+    /// Per-step probability that a compliant satellite in a congested tier
+    /// raises its orbit into the next tier up, if that tier is less full.
+    #[serde(default = "default_orbit_raising_rate")]
+    #[schemars(schema_with = "schema::probability_schedule")]
+    pub orbit_raising_rate: Schedule,
+}
+
+fn default_orbit_raising_rate() -> Schedule {
+    0.02.into()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OperatorConfig {
+    pub name: String,
+    /// Relative share of the initial population owned by the operator.
+    #[serde(default = "default_initial_share")]
+    #[schemars(range(min = 0.0))]
+    pub initial_share: f64,
+    /// Probability of one launch by this operator per step; defaults to
+    /// `policy.launch_rate`.
+    #[serde(default)]
+    #[schemars(schema_with = "schema::optional_probability_schedule")]
+    pub launch_rate: Option<Schedule>,
+    /// Probability that a new object of this operator follows mitigation
+    /// policy; defaults to `policy.compliance_rate`.
+    #[serde(default)]
+    #[schemars(schema_with = "schema::optional_probability_schedule")]
+    pub compliance_rate: Option<Schedule>,
+    /// Willingness to launch into congested tiers, in [0, 1]. At 0 the
+    /// operator always picks the least utilized tier, at 1 any tier.
+    #[serde(default = "default_risk_appetite")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub risk_appetite: f64,
+}
+
+fn default_days_per_step() -> f64 {
+    7.0
+}
+
+fn default_initial_share() -> f64 {
+    1.0
+}
+
+fn default_risk_appetite() -> f64 {
+    0.5
+}
+
+/// Closed interval new values are drawn uniformly from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValueRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ValueRange {
+    pub fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    pub fn sample(&self, rng: &mut SimulationRng) -> f64 {
+        if self.max > self.min {
+            rng.gen_range(self.min..=self.max)
+        } else {
+            self.min
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AgentPropertiesConfig {
+    /// Orbital inclination, in degrees.
+    pub inclination_deg: ValueRange,
+    /// Object mass, in kg.
+    pub mass_kg: ValueRange,
+    /// Cross-sectional area, in m².
+    pub area_m2: ValueRange,
+}
+
+impl Default for AgentPropertiesConfig {
+    fn default() -> Self {
+        Self {
+            inclination_deg: ValueRange::new(30.0, 98.0),
+            mass_kg: ValueRange::new(150.0, 1200.0),
+            area_m2: ValueRange::new(2.0, 18.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectMixConfig {
+    /// Fraction of initial objects that are defunct satellites.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub defunct_satellite: f64,
+    /// Fraction of initial objects that are spent rocket bodies.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub rocket_body: f64,
+    /// Fraction of initial objects that are debris fragments. The remainder
+    /// of the population are active satellites.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub debris_fragment: f64,
+    /// Probability that a launch leaves its upper stage in orbit.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub rocket_body_per_launch: f64,
+    /// Distributions physical properties of debris fragments are drawn from.
+    pub debris_properties: AgentPropertiesConfig,
+}
+
+impl ObjectMixConfig {
+    /// Draw the kind of one initial object.
+    pub fn sample_kind(&self, rng: &mut SimulationRng) -> ObjectKind {
+        let draw = rng.gen_range(0.0..1.0);
+        if draw < self.defunct_satellite {
+            ObjectKind::DefunctSatellite
+        } else if draw < self.defunct_satellite + self.rocket_body {
+            ObjectKind::RocketBody
+        } else if draw < self.defunct_satellite + self.rocket_body + self.debris_fragment {
+            ObjectKind::DebrisFragment
+        } else {
+            ObjectKind::ActiveSatellite
+        }
+    }
+}
+
+impl Default for ObjectMixConfig {
+    fn default() -> Self {
+        Self {
+            defunct_satellite: 0.1,
+            rocket_body: 0.05,
+            debris_fragment: 0.15,
+            rocket_body_per_launch: 0.2,
+            debris_properties: AgentPropertiesConfig {
+                inclination_deg: ValueRange::new(30.0, 98.0),
+                mass_kg: ValueRange::new(0.1, 10.0),
+                area_m2: ValueRange::new(0.01, 0.5),
+            },
+        }
+    }
+}
+
+/// Breakup settings, loosely following the NASA standard breakup model.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FragmentationConfig {
+    /// Whether collisions and cascading failures create debris at all.
+    pub enabled: bool,
+    /// Smallest fragment tracked, as a characteristic length in m. Smaller
+    /// values produce many more fragments.
+    #[schemars(range(min = 0.0))]
+    pub min_characteristic_length_m: f64,
+    /// Upper bound on fragments created by a single breakup.
+    pub max_fragments_per_breakup: usize,
+    /// Standard deviation of fragment altitudes around the parent, in km.
+    #[schemars(range(min = 0.0))]
+    pub altitude_spread_km: f64,
+}
+
+impl Default for FragmentationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_characteristic_length_m: 1.0,
+            max_fragments_per_breakup: 50,
+            altitude_spread_km: 25.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DragConfig {
+    /// Whether objects lose altitude to atmospheric drag.
+    pub enabled: bool,
+    /// Drag coefficient applied to every object.
+    #[schemars(range(min = 0.0))]
+    pub drag_coefficient: f64,
+    /// Altitude below which an object re-enters and is removed, in km.
+    #[schemars(range(min = 0.0))]
+    pub reentry_altitude_km: f64,
+    pub solar_cycle: SolarCycleConfig,
+}
+
+impl Default for DragConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            drag_coefficient: 2.2,
+            reentry_altitude_km: 120.0,
+            solar_cycle: SolarCycleConfig::default(),
+        }
+    }
+}
+
+/// Failure rates of active satellites' subsystems, per satellite-year.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubsystemConfig {
+    /// Loss of propulsion: the satellite can no longer maneuver or deorbit.
+    #[schemars(range(min = 0.0))]
+    pub propulsion_hazard_per_year: f64,
+    /// Loss of comms: the satellite can no longer be commanded.
+    #[schemars(range(min = 0.0))]
+    pub comms_hazard_per_year: f64,
+    /// Loss of power: the satellite is dead.
+    #[schemars(range(min = 0.0))]
+    pub power_hazard_per_year: f64,
+}
+
+impl SubsystemConfig {
+    pub fn hazard_per_year(&self, subsystem: Subsystem) -> f64 {
+        match subsystem {
+            Subsystem::Propulsion => self.propulsion_hazard_per_year,
+            Subsystem::Comms => self.comms_hazard_per_year,
+            Subsystem::Power => self.power_hazard_per_year,
+        }
+    }
+}
+
+impl Default for SubsystemConfig {
+    fn default() -> Self {
+        Self {
+            propulsion_hazard_per_year: 0.04,
+            comms_hazard_per_year: 0.02,
+            power_hazard_per_year: 0.015,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LifecycleConfig {
+    /// Time from arriving in orbit to entering service, in days.
+    #[schemars(range(min = 0.0))]
+    pub commissioning_days: f64,
+    /// Service life after which a satellite is retired, in years.
+    #[schemars(range(min = 0.0))]
+    pub design_life_years: f64,
+    /// Time from the start of a deorbit to re-entry, in days.
+    #[schemars(range(min = 0.0))]
+    pub deorbit_days: f64,
+}
+
+impl LifecycleConfig {
+    /// Design life in whole timesteps, at least one.
+    pub fn design_life_steps(&self, days_per_step: f64) -> usize {
+        (self.design_life_years * 365.25 / days_per_step)
+            .ceil()
+            .max(1.0) as usize
+    }
+}
+
+impl Default for LifecycleConfig {
+    fn default() -> Self {
+        Self {
+            commissioning_days: 30.0,
+            design_life_years: 7.0,
+            deorbit_days: 30.0,
+        }
+    }
+}
+
+/// Close-approach screening. A collision is a screened approach whose miss
+/// distance falls inside `screening_radius_km / warnings_per_collision`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConjunctionConfig {
+    /// Miss distance below which an approach raises a warning, in km.
+    #[schemars(range(min = 0.0))]
+    pub screening_radius_km: f64,
+    /// Warnings raised for every approach that would be a collision.
+    #[schemars(range(min = 1.0))]
+    pub warnings_per_collision: f64,
+    /// Miss distance below which operators maneuver out of the way, in km.
+    #[schemars(range(min = 0.0))]
+    pub maneuver_threshold_km: f64,
+}
+
+impl ConjunctionConfig {
+    /// Miss distance below which an approach is a collision, in km.
+    pub fn collision_radius_km(&self) -> f64 {
+        self.screening_radius_km / self.warnings_per_collision
+    }
+}
+
+impl Default for ConjunctionConfig {
+    fn default() -> Self {
+        Self {
+            screening_radius_km: 5.0,
+            warnings_per_collision: 20.0,
+            maneuver_threshold_km: 1.0,
+        }
+    }
+}
+
+/// Links between tiers through which a cascade in one tier can damage
+/// objects in another, e.g. neighbouring shells or crossing orbits.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpilloverConfig {
+    /// Probability that a cascade spills into each tier directly above or
+    /// below its own.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub neighbor_probability: f64,
+    /// Damage dealt to each object hit by a spillover, as a share of the
+    /// damage a cascade deals in its own tier.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub damage_scale: f64,
+    /// Explicit links by tier name. A link between neighbouring tiers
+    /// replaces `neighbor_probability` for that pair.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<TierLinkConfig>,
+}
+
+impl Default for SpilloverConfig {
+    fn default() -> Self {
+        Self {
+            neighbor_probability: 0.2,
+            damage_scale: 0.5,
+            links: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TierLinkConfig {
+    pub from: String,
+    pub to: String,
+    /// Probability that a cascade in one tier spills into the other.
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub probability: f64,
+    /// Only spill from `from` into `to`, not back.
+    #[serde(default)]
+    pub one_way: bool,
+}
+
+/// Solar activity, which heats and expands the upper atmosphere.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SolarCycleConfig {
+    /// Length of one solar cycle, in years.
+    #[schemars(range(min = 0.0))]
+    pub period_years: f64,
+    /// F10.7 radio flux at solar minimum and maximum, in sfu.
+    pub flux: ValueRange,
+    /// Years since the last solar minimum at the first timestep.
+    pub phase_years: f64,
+}
+
+impl Default for SolarCycleConfig {
+    fn default() -> Self {
+        Self {
+            period_years: 11.0,
+            flux: ValueRange::new(70.0, 200.0),
+            phase_years: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportingConfig {
+    /// Steps between status reports.
+    #[schemars(range(min = 1))]
+    pub report_interval: usize,
+    /// Soften report language when risk is elevated.
+    pub hedging_enabled: bool,
+    /// Add monitoring advice to critical reports.
+    pub confidence_modulation: bool,
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub ambiguity_level: f64,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            time_steps: 1000,
+            days_per_step: default_days_per_step(),
+            initial_agents: 500,
+            capacity_tiers: vec![
+                TierConfig {
+                    name: "LEO-Low".to_string(),
+                    min_altitude_km: Some(300.0),
+                    max_altitude_km: Some(550.0),
+                    max_capacity: 200,
+                    congestion_threshold: 0.7.into(),
+                    failure_base_rate: 0.001.into(),
+                    failure_curve: FailureCurve::default(),
+                    debris_degradation: DebrisDegradation::default(),
+                },
+                TierConfig {
+                    name: "LEO-Mid".to_string(),
+                    min_altitude_km: Some(550.0),
+                    max_altitude_km: Some(800.0),
+                    max_capacity: 300,
+                    congestion_threshold: 0.75.into(),
+                    failure_base_rate: 0.0008.into(),
+                    failure_curve: FailureCurve::default(),
+                    debris_degradation: DebrisDegradation::default(),
+                },
+                TierConfig {
+                    name: "LEO-High".to_string(),
+                    min_altitude_km: Some(800.0),
+                    max_altitude_km: Some(1200.0),
+                    max_capacity: 250,
+                    congestion_threshold: 0.8.into(),
+                    failure_base_rate: 0.0006.into(),
+                    failure_curve: FailureCurve::default(),
+                    debris_degradation: DebrisDegradation::default(),
+                },
+            ],
+            policy: PolicyConfig {
+                launch_rate: 0.02.into(),
+                mitigation_strength: 0.3.into(),
+                compliance_rate: 0.85.into(),
+                deorbit_threshold: 0.9.into(),
+                orbit_raising_rate: default_orbit_raising_rate(),
+            },
+            operators: Vec::new(),
+            reporting: ReportingConfig {
+                report_interval: 10,
+                hedging_enabled: true,
+                confidence_modulation: true,
+                ambiguity_level: 0.5,
+            },
+            agent_properties: AgentPropertiesConfig::default(),
+            object_mix: ObjectMixConfig::default(),
+            fragmentation: FragmentationConfig::default(),
+            drag: DragConfig::default(),
+            subsystems: SubsystemConfig::default(),
+            lifecycle: LifecycleConfig::default(),
+            conjunctions: ConjunctionConfig::default(),
+            spillover: SpilloverConfig::default(),
+            random_seed: Some(42),
+            interventions: Vec::new(),
+        }
+    }
+}
+
+impl SimulationConfig {
+    pub fn with_policy(mut self, policy: PolicyConfig) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_time_steps(mut self, steps: usize) -> Self {
+        self.time_steps = steps;
+        self
+    }
+
+    /// Distributions the physical properties of an object of `kind` are drawn from.
+    pub fn properties_for(&self, kind: ObjectKind) -> &AgentPropertiesConfig {
+        match kind {
+            ObjectKind::DebrisFragment => &self.object_mix.debris_properties,
+            _ => &self.agent_properties,
+        }
+    }
+}
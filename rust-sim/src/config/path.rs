@@ -0,0 +1,195 @@
+use crate::config::parameters::SimulationConfig;
+use serde_json::Value;
+use std::error::Error;
+
+/// One step of a dotted config path such as `capacity_tiers[1].max_capacity`.
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    AllIndices,
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment>, Box<dyn Error>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest) = match part.find('[') {
+            Some(i) => (&part[..i], &part[i..]),
+            None => (part, ""),
+        };
+        if key.is_empty() {
+            return Err(format!("invalid config path '{}': empty field name", path).into());
+        }
+        segments.push(Segment::Key(key.to_string()));
+
+        while !rest.is_empty() {
+            let close = rest
+                .find(']')
+                .filter(|_| rest.starts_with('['))
+                .ok_or_else(|| format!("invalid config path '{}': malformed index", path))?;
+            let index = &rest[1..close];
+            if index == "*" {
+                segments.push(Segment::AllIndices);
+            } else {
+                let i = index.parse().map_err(|_| {
+                    format!("invalid config path '{}': bad index '{}'", path, index)
+                })?;
+                segments.push(Segment::Index(i));
+            }
+            rest = &rest[close + 1..];
+        }
+    }
+    Ok(segments)
+}
+
+fn set_in(
+    node: &mut Value,
+    segments: &[Segment],
+    value: &Value,
+    path: &str,
+) -> Result<(), Box<dyn Error>> {
+    let Some((head, tail)) = segments.split_first() else {
+        *node = value.clone();
+        return Ok(());
+    };
+
+    match head {
+        Segment::Key(key) => {
+            let child = node
+                .as_object_mut()
+                .and_then(|obj| obj.get_mut(key))
+                .ok_or_else(|| format!("unknown config field '{}' in '{}'", key, path))?;
+            set_in(child, tail, value, path)
+        }
+        Segment::Index(i) => {
+            let items = node
+                .as_array_mut()
+                .ok_or_else(|| format!("'{}' indexes a field that is not a list", path))?;
+            let len = items.len();
+            let child = items.get_mut(*i).ok_or_else(|| {
+                format!("index {} out of range in '{}' (length {})", i, path, len)
+            })?;
+            set_in(child, tail, value, path)
+        }
+        Segment::AllIndices => {
+            let items = node
+                .as_array_mut()
+                .ok_or_else(|| format!("'{}' indexes a field that is not a list", path))?;
+            for child in items.iter_mut() {
+                set_in(child, tail, value, path)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn get_in(node: &Value, segments: &[Segment], path: &str) -> Result<Value, Box<dyn Error>> {
+    let Some((head, tail)) = segments.split_first() else {
+        return Ok(node.clone());
+    };
+
+    match head {
+        Segment::Key(key) => {
+            let child = node
+                .get(key)
+                .ok_or_else(|| format!("unknown config field '{}' in '{}'", key, path))?;
+            get_in(child, tail, path)
+        }
+        Segment::Index(i) => {
+            let items = node
+                .as_array()
+                .ok_or_else(|| format!("'{}' indexes a field that is not a list", path))?;
+            let child = items.get(*i).ok_or_else(|| {
+                format!(
+                    "index {} out of range in '{}' (length {})",
+                    i,
+                    path,
+                    items.len()
+                )
+            })?;
+            get_in(child, tail, path)
+        }
+        Segment::AllIndices => {
+            let items = node
+                .as_array()
+                .ok_or_else(|| format!("'{}' indexes a field that is not a list", path))?;
+            items
+                .iter()
+                .map(|child| get_in(child, tail, path))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Read a field addressed by a dotted path, as accepted by
+    /// [`SimulationConfig::set_path`]. `[*]` yields a list of every entry's value.
+    pub fn get_path(&self, path: &str) -> Result<Value, Box<dyn Error>> {
+        let segments = parse_path(path)?;
+        get_in(&serde_json::to_value(self)?, &segments, path)
+    }
+
+    /// Set a field addressed by a dotted path, e.g. `policy.launch_rate` or
+    /// `capacity_tiers[1].max_capacity`. `[*]` applies the value to every entry
+    /// of a list. The value is type-checked by deserializing the updated config.
+    pub fn set_path(&mut self, path: &str, value: Value) -> Result<(), Box<dyn Error>> {
+        let segments = parse_path(path)?;
+        let mut tree = serde_json::to_value(&*self)?;
+        set_in(&mut tree, &segments, &value, path)?;
+        *self = serde_json::from_value(tree)
+            .map_err(|e| format!("invalid value {} for '{}': {}", value, path, e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_set_nested_and_indexed() {
+        let mut config = SimulationConfig::default();
+        config.set_path("policy.launch_rate", json!(0.05)).unwrap();
+        config
+            .set_path("capacity_tiers[1].max_capacity", json!(400))
+            .unwrap();
+        assert_eq!(config.policy.launch_rate, 0.05.into());
+        assert_eq!(config.capacity_tiers[1].max_capacity, 400);
+        assert_eq!(config.capacity_tiers[0].max_capacity, 200);
+        assert_eq!(
+            config.get_path("capacity_tiers[1].max_capacity").unwrap(),
+            json!(400)
+        );
+        assert_eq!(
+            config.get_path("capacity_tiers[*].max_capacity").unwrap(),
+            json!([200, 400, 250])
+        );
+    }
+
+    #[test]
+    fn test_set_wildcard() {
+        let mut config = SimulationConfig::default();
+        config
+            .set_path("capacity_tiers[*].failure_base_rate", json!(0.002))
+            .unwrap();
+        assert!(config
+            .capacity_tiers
+            .iter()
+            .all(|t| t.failure_base_rate == 0.002.into()));
+    }
+
+    #[test]
+    fn test_rejects_bad_paths_and_types() {
+        let mut config = SimulationConfig::default();
+        assert!(config.set_path("policy.launch_rat", json!(0.1)).is_err());
+        assert!(config
+            .set_path("capacity_tiers[9].name", json!("x"))
+            .is_err());
+        assert!(config
+            .set_path("capacity_tiers[0].max_capacity", json!(2.5))
+            .is_err());
+        assert!(config.set_path("policy..launch_rate", json!(0.1)).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::config::parameters::SimulationConfig;
+
+/// A named, fully specified reference configuration.
+pub struct ScenarioPreset {
+    pub name: &'static str,
+    pub description: &'static str,
+    build: fn() -> SimulationConfig,
+}
+
+impl ScenarioPreset {
+    pub fn config(&self) -> SimulationConfig {
+        (self.build)()
+    }
+}
+
+pub const SCENARIOS: &[ScenarioPreset] = &[
+    ScenarioPreset {
+        name: "baseline",
+        description: "The default configuration: moderate occupancy, steady launches, \
+                      mostly compliant operators.",
+        build: SimulationConfig::default,
+    },
+    ScenarioPreset {
+        name: "megaconstellation-buildout",
+        description: "Sustained high launch cadence into roomier shells, to study how \
+                      quickly a buildout pushes tiers from stable into congestion.",
+        build: megaconstellation_buildout,
+    },
+    ScenarioPreset {
+        name: "kessler-onset",
+        description: "Shells already near capacity with elevated base failure rates and \
+                      weak mitigation, so cascading failures are expected early.",
+        build: kessler_onset,
+    },
+    ScenarioPreset {
+        name: "strict-regulation",
+        description: "Low launch rate, near-universal compliance, strong mitigation and \
+                      an aggressive deorbit threshold: the best case for policy.",
+        build: strict_regulation,
+    },
+    ScenarioPreset {
+        name: "non-compliant-operators",
+        description: "Default traffic, but most operators ignore mitigation and deorbit \
+                      only at the last moment, isolating the effect of compliance.",
+        build: non_compliant_operators,
+    },
+    ScenarioPreset {
+        name: "under-utilized",
+        description: "Sparse shells and rare launches; a control run where reports \
+                      should stay stable and risk near its base rate.",
+        build: under_utilized,
+    },
+];
+
+fn megaconstellation_buildout() -> SimulationConfig {
+    let mut config = SimulationConfig {
+        initial_agents: 400,
+        ..SimulationConfig::default()
+    };
+    for tier in &mut config.capacity_tiers {
+        tier.max_capacity += tier.max_capacity / 2;
+    }
+    config.policy.launch_rate = 0.5.into();
+    config
+}
+
+fn kessler_onset() -> SimulationConfig {
+    let mut config = SimulationConfig {
+        initial_agents: 720,
+        ..SimulationConfig::default()
+    };
+    for tier in &mut config.capacity_tiers {
+        tier.failure_base_rate = tier.failure_base_rate.scaled(3.0);
+    }
+    config.policy.mitigation_strength = 0.05.into();
+    config.policy.deorbit_threshold = 0.98.into();
+    config
+}
+
+fn strict_regulation() -> SimulationConfig {
+    let mut config = SimulationConfig::default();
+    config.policy.launch_rate = 0.01.into();
+    config.policy.mitigation_strength = 0.8.into();
+    config.policy.compliance_rate = 0.98.into();
+    config.policy.deorbit_threshold = 0.75.into();
+    config
+}
+
+fn non_compliant_operators() -> SimulationConfig {
+    let mut config = SimulationConfig::default();
+    config.policy.mitigation_strength = 0.1.into();
+    config.policy.compliance_rate = 0.3.into();
+    config.policy.deorbit_threshold = 0.98.into();
+    config
+}
+
+fn under_utilized() -> SimulationConfig {
+    let mut config = SimulationConfig {
+        initial_agents: 150,
+        ..SimulationConfig::default()
+    };
+    config.policy.launch_rate = 0.005.into();
+    config
+}
+
+impl SimulationConfig {
+    /// Look up a built-in scenario by name.
+    pub fn preset(name: &str) -> Option<Self> {
+        SCENARIOS
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.config())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_valid_and_unique() {
+        for (i, scenario) in SCENARIOS.iter().enumerate() {
+            assert!(
+                scenario.config().validate().is_ok(),
+                "{} is invalid",
+                scenario.name
+            );
+            assert!(SCENARIOS[..i].iter().all(|s| s.name != scenario.name));
+        }
+        assert!(SimulationConfig::preset("kessler-onset").is_some());
+        assert!(SimulationConfig::preset("nope").is_none());
+    }
+}
@@ -0,0 +1,212 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// A numeric parameter that may vary over the run. A bare number in a
+/// scenario file is a constant; a table with a `type` key is a time-varying
+/// schedule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum Schedule {
+    Constant(f64),
+    Varying(VaryingSchedule),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VaryingSchedule {
+    /// Linear interpolation between `[timestep, value]` keyframes, held
+    /// constant before the first and after the last keyframe.
+    Piecewise { keyframes: Vec<(usize, f64)> },
+    /// `initial` until the first change, then each `[timestep, value]` from
+    /// its timestep onwards.
+    Step {
+        initial: f64,
+        changes: Vec<(usize, f64)>,
+    },
+    /// `mean + amplitude * sin(2π t / period + phase)`.
+    Sinusoid {
+        mean: f64,
+        amplitude: f64,
+        period: f64,
+        #[serde(default)]
+        phase: f64,
+    },
+}
+
+impl Schedule {
+    pub fn value_at(&self, t: usize) -> f64 {
+        match self {
+            Schedule::Constant(v) => *v,
+            Schedule::Varying(VaryingSchedule::Piecewise { keyframes }) => {
+                let Some(&(t0, v0)) = keyframes.first() else {
+                    return f64::NAN;
+                };
+                if t <= t0 {
+                    return v0;
+                }
+                for pair in keyframes.windows(2) {
+                    let ((ta, va), (tb, vb)) = (pair[0], pair[1]);
+                    if t <= tb {
+                        if tb == ta {
+                            return vb;
+                        }
+                        let frac = (t - ta) as f64 / (tb - ta) as f64;
+                        return va + (vb - va) * frac;
+                    }
+                }
+                keyframes[keyframes.len() - 1].1
+            }
+            Schedule::Varying(VaryingSchedule::Step { initial, changes }) => changes
+                .iter()
+                .take_while(|(at, _)| *at <= t)
+                .last()
+                .map_or(*initial, |(_, v)| *v),
+            Schedule::Varying(VaryingSchedule::Sinusoid {
+                mean,
+                amplitude,
+                period,
+                phase,
+            }) => mean + amplitude * (2.0 * PI * t as f64 / period + phase).sin(),
+        }
+    }
+
+    /// Smallest and largest values the schedule can take over any run.
+    pub fn bounds(&self) -> (f64, f64) {
+        let fold = |values: &mut dyn Iterator<Item = f64>| {
+            values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v), hi.max(v))
+            })
+        };
+        match self {
+            Schedule::Constant(v) => (*v, *v),
+            Schedule::Varying(VaryingSchedule::Piecewise { keyframes }) => {
+                fold(&mut keyframes.iter().map(|(_, v)| *v))
+            }
+            Schedule::Varying(VaryingSchedule::Step { initial, changes }) => {
+                fold(&mut std::iter::once(*initial).chain(changes.iter().map(|(_, v)| *v)))
+            }
+            Schedule::Varying(VaryingSchedule::Sinusoid {
+                mean, amplitude, ..
+            }) => (mean - amplitude.abs(), mean + amplitude.abs()),
+        }
+    }
+
+    /// Describe why the schedule cannot be evaluated, if it is malformed.
+    pub fn structural_error(&self) -> Option<&'static str> {
+        match self {
+            Schedule::Constant(_) => None,
+            Schedule::Varying(VaryingSchedule::Piecewise { keyframes }) => {
+                if keyframes.is_empty() {
+                    Some("piecewise schedule needs at least one keyframe")
+                } else if keyframes.windows(2).any(|w| w[1].0 < w[0].0) {
+                    Some("piecewise keyframes must be in timestep order")
+                } else {
+                    None
+                }
+            }
+            Schedule::Varying(VaryingSchedule::Step { changes, .. }) => {
+                if changes.windows(2).any(|w| w[1].0 < w[0].0) {
+                    Some("step changes must be in timestep order")
+                } else {
+                    None
+                }
+            }
+            Schedule::Varying(VaryingSchedule::Sinusoid { period, .. }) => {
+                if period.is_finite() && *period > 0.0 {
+                    None
+                } else {
+                    Some("sinusoid period must be greater than 0")
+                }
+            }
+        }
+    }
+
+    /// The same schedule with every value multiplied by `factor`.
+    pub fn scaled(&self, factor: f64) -> Self {
+        let scale_points =
+            |points: &[(usize, f64)]| points.iter().map(|(t, v)| (*t, v * factor)).collect();
+        match self {
+            Schedule::Constant(v) => Schedule::Constant(v * factor),
+            Schedule::Varying(VaryingSchedule::Piecewise { keyframes }) => {
+                Schedule::Varying(VaryingSchedule::Piecewise {
+                    keyframes: scale_points(keyframes),
+                })
+            }
+            Schedule::Varying(VaryingSchedule::Step { initial, changes }) => {
+                Schedule::Varying(VaryingSchedule::Step {
+                    initial: initial * factor,
+                    changes: scale_points(changes),
+                })
+            }
+            Schedule::Varying(VaryingSchedule::Sinusoid {
+                mean,
+                amplitude,
+                period,
+                phase,
+            }) => Schedule::Varying(VaryingSchedule::Sinusoid {
+                mean: mean * factor,
+                amplitude: amplitude * factor,
+                period: *period,
+                phase: *phase,
+            }),
+        }
+    }
+}
+
+impl From<f64> for Schedule {
+    fn from(value: f64) -> Self {
+        Schedule::Constant(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piecewise() {
+        let s = Schedule::Varying(VaryingSchedule::Piecewise {
+            keyframes: vec![(100, 0.0), (200, 1.0), (300, 0.5)],
+        });
+        assert_eq!(s.value_at(0), 0.0);
+        assert_eq!(s.value_at(150), 0.5);
+        assert_eq!(s.value_at(250), 0.75);
+        assert_eq!(s.value_at(1000), 0.5);
+        assert_eq!(s.bounds(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_step() {
+        let s = Schedule::Varying(VaryingSchedule::Step {
+            initial: 0.02,
+            changes: vec![(300, 0.1), (600, 0.05)],
+        });
+        assert_eq!(s.value_at(299), 0.02);
+        assert_eq!(s.value_at(300), 0.1);
+        assert_eq!(s.value_at(700), 0.05);
+    }
+
+    #[test]
+    fn test_sinusoid() {
+        let s = Schedule::Varying(VaryingSchedule::Sinusoid {
+            mean: 0.5,
+            amplitude: 0.2,
+            period: 100.0,
+            phase: 0.0,
+        });
+        assert!((s.value_at(25) - 0.7).abs() < 1e-12);
+        assert!((s.value_at(75) - 0.3).abs() < 1e-12);
+        assert_eq!(s.bounds(), (0.3, 0.7));
+    }
+
+    #[test]
+    fn test_deserialize_number_or_table() {
+        let c: Schedule = serde_json::from_str("0.3").unwrap();
+        assert_eq!(c, Schedule::Constant(0.3));
+        let s: Schedule =
+            serde_json::from_str(r#"{"type": "step", "initial": 0.1, "changes": [[5, 0.2]]}"#)
+                .unwrap();
+        assert_eq!(s.value_at(5), 0.2);
+    }
+}
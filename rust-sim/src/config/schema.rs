@@ -0,0 +1,118 @@
+use crate::config::parameters::SimulationConfig;
+use crate::config::schedule::VaryingSchedule;
+use schemars::{json_schema, Schema, SchemaGenerator};
+use serde_json::Value;
+
+/// JSON Schema (draft 2020-12) for scenario files, with defaults taken from
+/// `SimulationConfig::default()`.
+pub fn simulation_config_schema() -> Value {
+    let schema = schemars::schema_for!(SimulationConfig);
+    let mut schema = serde_json::to_value(schema).expect("schema serializes");
+    let defaults = serde_json::to_value(SimulationConfig::default()).expect("config serializes");
+
+    insert_defaults(&mut schema, &defaults);
+    for (def, field) in [
+        ("PolicyConfig", "policy"),
+        ("ReportingConfig", "reporting"),
+        ("ObjectMixConfig", "object_mix"),
+        ("FragmentationConfig", "fragmentation"),
+        ("DragConfig", "drag"),
+        ("SubsystemConfig", "subsystems"),
+        ("LifecycleConfig", "lifecycle"),
+        ("ConjunctionConfig", "conjunctions"),
+        ("SpilloverConfig", "spillover"),
+    ] {
+        if let Some(target) = schema.pointer_mut(&format!("/$defs/{}", def)) {
+            insert_defaults(target, &defaults[field]);
+        }
+    }
+    schema
+}
+
+fn insert_defaults(schema: &mut Value, defaults: &Value) {
+    let (Some(properties), Some(defaults)) = (
+        schema.get_mut("properties").and_then(Value::as_object_mut),
+        defaults.as_object(),
+    ) else {
+        return;
+    };
+    for (name, property) in properties.iter_mut() {
+        if let (Some(default), Some(property)) = (defaults.get(name), property.as_object_mut()) {
+            property.insert("default".to_string(), default.clone());
+        }
+    }
+}
+
+fn scheduled_number(generator: &mut SchemaGenerator, number: Schema) -> Schema {
+    let varying = generator.subschema_for::<VaryingSchedule>();
+    json_schema!({
+        "anyOf": [number, varying]
+    })
+}
+
+/// A number in [0, 1], or a schedule whose values must stay in [0, 1].
+pub fn probability_schedule(generator: &mut SchemaGenerator) -> Schema {
+    scheduled_number(
+        generator,
+        json_schema!({ "type": "number", "minimum": 0.0, "maximum": 1.0 }),
+    )
+}
+
+/// Like [`probability_schedule`], but may be omitted or null.
+pub fn optional_probability_schedule(generator: &mut SchemaGenerator) -> Schema {
+    let schedule = probability_schedule(generator);
+    json_schema!({
+        "anyOf": [schedule, { "type": "null" }]
+    })
+}
+
+/// A number strictly between 0 and 1, or a schedule within that range.
+pub fn open_unit_schedule(generator: &mut SchemaGenerator) -> Schema {
+    scheduled_number(
+        generator,
+        json_schema!({ "type": "number", "exclusiveMinimum": 0.0, "exclusiveMaximum": 1.0 }),
+    )
+}
+
+/// A number greater than 0, or a schedule of positive values.
+pub fn positive_schedule(generator: &mut SchemaGenerator) -> Schema {
+    scheduled_number(
+        generator,
+        json_schema!({ "type": "number", "exclusiveMinimum": 0.0 }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_has_descriptions_ranges_and_defaults() {
+        let schema = simulation_config_schema();
+        assert_eq!(schema["properties"]["time_steps"]["default"], 1000);
+        assert!(schema["properties"]["time_steps"]["description"].is_string());
+
+        let policy = &schema["$defs"]["PolicyConfig"]["properties"];
+        assert_eq!(policy["launch_rate"]["default"], 0.02);
+        assert_eq!(policy["launch_rate"]["anyOf"][0]["maximum"], 1.0);
+        assert_eq!(
+            schema["$defs"]["TierConfig"]["properties"]["max_capacity"]["minimum"],
+            1
+        );
+        assert_eq!(schema["additionalProperties"], false);
+    }
+
+    #[test]
+    fn test_checked_in_schema_is_current() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../scenarios/simulation_config.schema.json"
+        );
+        let checked_in: Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert!(
+            checked_in == simulation_config_schema(),
+            "scenarios/simulation_config.schema.json is stale; regenerate with `simulator schema -o`"
+        );
+    }
+}
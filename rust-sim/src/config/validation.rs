@@ -0,0 +1,786 @@
+use crate::config::interventions::InterventionAction;
+use crate::config::parameters::{AgentPropertiesConfig, SimulationConfig, ValueRange};
+use crate::config::schedule::Schedule;
+use crate::models::failure_rate::FailureRateModel;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationErrorKind {
+    /// Value must be a probability in [0, 1].
+    NotAProbability(f64),
+    /// Value must lie strictly between 0 and 1.
+    NotInOpenUnitInterval(f64),
+    /// Value must be a finite number greater than zero.
+    NotPositive(f64),
+    /// Integer value must be greater than zero.
+    Zero,
+    /// Collection must contain at least one entry.
+    Empty,
+    /// Time-varying schedule cannot be evaluated.
+    MalformedSchedule(&'static str),
+    /// Failure-rate curve cannot be evaluated.
+    MalformedCurve(&'static str),
+    /// Intervention refers to a tier that does not exist at that point.
+    UnknownTier(String),
+    /// Intervention cannot be applied to the configuration.
+    InvalidIntervention(String),
+    /// Range bounds are reversed or not finite.
+    InvalidRange { min: f64, max: f64 },
+    /// Altitude band overlaps the band of the named tier.
+    OverlappingBand(String),
+    /// Value must lie within the given inclusive bounds.
+    OutOfBounds { value: f64, min: f64, max: f64 },
+    /// Name is already used by an earlier entry.
+    DuplicateName(String),
+    /// Timestep is not reached by a run of the given number of steps.
+    AfterEnd(usize),
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationErrorKind::NotAProbability(v) => {
+                write!(f, "must be a probability in [0, 1], got {}", v)
+            }
+            ValidationErrorKind::NotInOpenUnitInterval(v) => {
+                write!(f, "must be strictly between 0 and 1, got {}", v)
+            }
+            ValidationErrorKind::NotPositive(v) => {
+                write!(f, "must be a finite number greater than 0, got {}", v)
+            }
+            ValidationErrorKind::Zero => write!(f, "must be greater than 0"),
+            ValidationErrorKind::Empty => write!(f, "must not be empty"),
+            ValidationErrorKind::MalformedSchedule(reason)
+            | ValidationErrorKind::MalformedCurve(reason) => {
+                write!(f, "{}", reason)
+            }
+            ValidationErrorKind::UnknownTier(name) => write!(f, "no tier named '{}'", name),
+            ValidationErrorKind::InvalidIntervention(reason) => write!(f, "{}", reason),
+            ValidationErrorKind::InvalidRange { min, max } => {
+                write!(
+                    f,
+                    "needs finite bounds with min <= max, got [{}, {}]",
+                    min, max
+                )
+            }
+            ValidationErrorKind::OverlappingBand(other) => {
+                write!(f, "altitude band overlaps tier '{}'", other)
+            }
+            ValidationErrorKind::OutOfBounds { value, min, max } => {
+                write!(f, "must be in [{}, {}], got {}", min, max, value)
+            }
+            ValidationErrorKind::DuplicateName(name) => {
+                write!(f, "name '{}' is used more than once", name)
+            }
+            ValidationErrorKind::AfterEnd(time_steps) => {
+                write!(f, "is never reached by a run of {} steps", time_steps)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// Dotted path to the offending field, e.g. `capacity_tiers[1].max_capacity`.
+    pub field: String,
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    pub fn new(field: impl Into<String>, kind: ValidationErrorKind) -> Self {
+        Self {
+            field: field.into(),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.kind)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Every problem found in a configuration, so they can be fixed in one pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationErrors {
+    pub errors: Vec<ValidationError>,
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid simulation config ({} errors)",
+            self.errors.len()
+        )?;
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationErrors {}
+
+#[derive(Default)]
+struct Validator {
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    fn probability(&mut self, field: String, value: f64) {
+        if !(0.0..=1.0).contains(&value) {
+            self.push(field, ValidationErrorKind::NotAProbability(value));
+        }
+    }
+
+    fn open_unit(&mut self, field: String, value: f64) {
+        if !(value > 0.0 && value < 1.0) {
+            self.push(field, ValidationErrorKind::NotInOpenUnitInterval(value));
+        }
+    }
+
+    fn positive(&mut self, field: String, value: f64) {
+        if !(value.is_finite() && value > 0.0) {
+            self.push(field, ValidationErrorKind::NotPositive(value));
+        }
+    }
+
+    fn range(&mut self, field: String, range: &ValueRange) {
+        if !(range.min.is_finite() && range.max.is_finite() && range.min <= range.max) {
+            self.push(
+                field,
+                ValidationErrorKind::InvalidRange {
+                    min: range.min,
+                    max: range.max,
+                },
+            );
+        }
+    }
+
+    fn bounded(&mut self, field: String, value: f64, min: f64, max: f64) {
+        if !(min..=max).contains(&value) {
+            self.push(field, ValidationErrorKind::OutOfBounds { value, min, max });
+        }
+    }
+
+    fn properties(&mut self, prefix: &str, properties: &AgentPropertiesConfig) {
+        let inclination = &properties.inclination_deg;
+        self.range(format!("{}.inclination_deg", prefix), inclination);
+        self.bounded(
+            format!("{}.inclination_deg.min", prefix),
+            inclination.min,
+            0.0,
+            180.0,
+        );
+        self.bounded(
+            format!("{}.inclination_deg.max", prefix),
+            inclination.max,
+            0.0,
+            180.0,
+        );
+        self.range(format!("{}.mass_kg", prefix), &properties.mass_kg);
+        self.positive(format!("{}.mass_kg.min", prefix), properties.mass_kg.min);
+        self.range(format!("{}.area_m2", prefix), &properties.area_m2);
+        self.positive(format!("{}.area_m2.min", prefix), properties.area_m2.min);
+    }
+
+    fn nonzero(&mut self, field: impl Into<String>, value: usize) {
+        if value == 0 {
+            self.push(field, ValidationErrorKind::Zero);
+        }
+    }
+
+    /// Apply a scalar check to the extremes of a schedule, reporting at most
+    /// one error for the field.
+    fn schedule(
+        &mut self,
+        field: impl Into<String>,
+        schedule: &Schedule,
+        check: fn(&mut Self, String, f64),
+    ) {
+        let field = field.into();
+        if let Some(reason) = schedule.structural_error() {
+            self.push(field, ValidationErrorKind::MalformedSchedule(reason));
+            return;
+        }
+        let (lo, hi) = schedule.bounds();
+        let before = self.errors.len();
+        check(self, field.clone(), lo);
+        if self.errors.len() == before && hi != lo {
+            check(self, field, hi);
+        }
+    }
+
+    fn push(&mut self, field: impl Into<String>, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError::new(field, kind));
+    }
+}
+
+impl SimulationConfig {
+    /// Check the configuration for values that would make the engine panic or
+    /// produce meaningless output. All errors are collected, not just the first.
+    pub fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut v = Validator::default();
+        self.validate_fields(&mut v);
+        self.validate_spillover_links(&mut v);
+        if v.errors.is_empty() {
+            self.validate_interventions(&mut v);
+        }
+
+        if v.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors { errors: v.errors })
+        }
+    }
+
+    /// Replay interventions on a copy of the config so that every parameter
+    /// they set, and every tier they add, is checked like the base config.
+    fn validate_interventions(&self, v: &mut Validator) {
+        let mut evolved = self.clone();
+        evolved.interventions.clear();
+
+        let mut order: Vec<usize> = (0..self.interventions.len()).collect();
+        order.sort_by_key(|&i| self.interventions[i].at);
+
+        for i in order {
+            let path = format!("interventions[{}]", i);
+            if self.interventions[i].at >= self.time_steps {
+                v.push(
+                    format!("{}.at", path),
+                    ValidationErrorKind::AfterEnd(self.time_steps),
+                );
+            }
+            let tiers_before = evolved.capacity_tiers.len();
+            let action = &self.interventions[i].action;
+            match action {
+                InterventionAction::SetParameter { field, value } => {
+                    if let Err(e) = evolved.set_path(field, value.clone()) {
+                        v.push(
+                            format!("{}.field", path),
+                            ValidationErrorKind::InvalidIntervention(e.to_string()),
+                        );
+                    }
+                }
+                InterventionAction::ForceDeorbit { tier, fraction, .. } => {
+                    v.probability(format!("{}.fraction", path), *fraction);
+                    if let Some(name) = tier {
+                        if !evolved.capacity_tiers.iter().any(|t| &t.name == name) {
+                            v.push(
+                                format!("{}.tier", path),
+                                ValidationErrorKind::UnknownTier(name.clone()),
+                            );
+                        }
+                    }
+                }
+                InterventionAction::AddTier { tier } => {
+                    evolved.capacity_tiers.push(tier.clone());
+                }
+            }
+
+            let mut after = Validator::default();
+            evolved.validate_fields(&mut after);
+            for error in after.errors {
+                let field = intervention_field(&path, action, tiers_before, &error.field);
+                v.push(field, error.kind);
+            }
+            if !v.errors.is_empty() {
+                return;
+            }
+        }
+    }
+
+    /// Links may name tiers that an intervention adds later in the run.
+    fn validate_spillover_links(&self, v: &mut Validator) {
+        let added = self.interventions.iter().filter_map(|i| match &i.action {
+            InterventionAction::AddTier { tier } => Some(&tier.name),
+            _ => None,
+        });
+        let names: Vec<&String> = self
+            .capacity_tiers
+            .iter()
+            .map(|t| &t.name)
+            .chain(added)
+            .collect();
+        for (i, link) in self.spillover.links.iter().enumerate() {
+            let path = format!("spillover.links[{}]", i);
+            for (field, name) in [("from", &link.from), ("to", &link.to)] {
+                if !names.contains(&name) {
+                    v.push(
+                        format!("{}.{}", path, field),
+                        ValidationErrorKind::UnknownTier(name.clone()),
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_fields(&self, v: &mut Validator) {
+        if self.capacity_tiers.is_empty() {
+            v.push("capacity_tiers", ValidationErrorKind::Empty);
+        }
+        for (i, tier) in self.capacity_tiers.iter().enumerate() {
+            let path = format!("capacity_tiers[{}]", i);
+            if self.capacity_tiers[..i].iter().any(|t| t.name == tier.name) {
+                v.push(
+                    format!("{}.name", path),
+                    ValidationErrorKind::DuplicateName(tier.name.clone()),
+                );
+            }
+            v.nonzero(format!("{}.max_capacity", path), tier.max_capacity);
+            let (min_altitude, max_altitude) = tier.band(i);
+            v.bounded(
+                format!("{}.min_altitude_km", path),
+                min_altitude,
+                0.0,
+                f64::MAX,
+            );
+            if max_altitude.partial_cmp(&min_altitude) != Some(std::cmp::Ordering::Greater) {
+                v.push(
+                    format!("{}.max_altitude_km", path),
+                    ValidationErrorKind::InvalidRange {
+                        min: min_altitude,
+                        max: max_altitude,
+                    },
+                );
+            }
+            if let Some(other) = self.capacity_tiers[..i]
+                .iter()
+                .enumerate()
+                .find_map(|(j, o)| {
+                    let (other_min, other_max) = o.band(j);
+                    (min_altitude < other_max && other_min < max_altitude).then_some(o)
+                })
+            {
+                v.push(
+                    format!("{}.min_altitude_km", path),
+                    ValidationErrorKind::OverlappingBand(other.name.clone()),
+                );
+            }
+            v.schedule(
+                format!("{}.congestion_threshold", path),
+                &tier.congestion_threshold,
+                Validator::open_unit,
+            );
+            v.schedule(
+                format!("{}.failure_base_rate", path),
+                &tier.failure_base_rate,
+                Validator::probability,
+            );
+            let degradation = &tier.debris_degradation;
+            v.bounded(
+                format!("{}.debris_degradation.capacity_per_fragment", path),
+                degradation.capacity_per_fragment,
+                0.0,
+                f64::MAX,
+            );
+            if !(degradation.min_capacity_fraction > 0.0
+                && degradation.min_capacity_fraction <= 1.0)
+            {
+                v.push(
+                    format!("{}.debris_degradation.min_capacity_fraction", path),
+                    ValidationErrorKind::OutOfBounds {
+                        value: degradation.min_capacity_fraction,
+                        min: 0.0,
+                        max: 1.0,
+                    },
+                );
+            }
+            if let Some(reason) = tier.failure_curve.structural_error() {
+                v.push(
+                    format!("{}.failure_curve", path),
+                    ValidationErrorKind::MalformedCurve(reason),
+                );
+            }
+        }
+
+        let policy = &self.policy;
+        v.schedule(
+            "policy.launch_rate",
+            &policy.launch_rate,
+            Validator::probability,
+        );
+        v.schedule(
+            "policy.mitigation_strength",
+            &policy.mitigation_strength,
+            Validator::probability,
+        );
+        v.schedule(
+            "policy.compliance_rate",
+            &policy.compliance_rate,
+            Validator::probability,
+        );
+        v.schedule(
+            "policy.deorbit_threshold",
+            &policy.deorbit_threshold,
+            Validator::positive,
+        );
+        v.schedule(
+            "policy.orbit_raising_rate",
+            &policy.orbit_raising_rate,
+            Validator::probability,
+        );
+
+        for (i, operator) in self.operators.iter().enumerate() {
+            let path = format!("operators[{}]", i);
+            if self.operators[..i].iter().any(|o| o.name == operator.name) {
+                v.push(
+                    format!("{}.name", path),
+                    ValidationErrorKind::DuplicateName(operator.name.clone()),
+                );
+            }
+            v.bounded(
+                format!("{}.initial_share", path),
+                operator.initial_share,
+                0.0,
+                f64::MAX,
+            );
+            for (field, schedule) in [
+                ("launch_rate", &operator.launch_rate),
+                ("compliance_rate", &operator.compliance_rate),
+            ] {
+                if let Some(schedule) = schedule {
+                    v.schedule(
+                        format!("{}.{}", path, field),
+                        schedule,
+                        Validator::probability,
+                    );
+                }
+            }
+            v.probability(format!("{}.risk_appetite", path), operator.risk_appetite);
+        }
+        if !self.operators.is_empty() {
+            v.positive(
+                "operators".to_string(),
+                self.operators.iter().map(|o| o.initial_share).sum(),
+            );
+        }
+
+        v.properties("agent_properties", &self.agent_properties);
+
+        let mix = &self.object_mix;
+        v.probability(
+            "object_mix.defunct_satellite".to_string(),
+            mix.defunct_satellite,
+        );
+        v.probability("object_mix.rocket_body".to_string(), mix.rocket_body);
+        v.probability(
+            "object_mix.debris_fragment".to_string(),
+            mix.debris_fragment,
+        );
+        v.bounded(
+            "object_mix".to_string(),
+            mix.defunct_satellite + mix.rocket_body + mix.debris_fragment,
+            0.0,
+            1.0,
+        );
+        v.probability(
+            "object_mix.rocket_body_per_launch".to_string(),
+            mix.rocket_body_per_launch,
+        );
+        v.properties("object_mix.debris_properties", &mix.debris_properties);
+
+        v.positive("days_per_step".to_string(), self.days_per_step);
+        let lifecycle = &self.lifecycle;
+        v.bounded(
+            "lifecycle.commissioning_days".to_string(),
+            lifecycle.commissioning_days,
+            0.0,
+            f64::MAX,
+        );
+        v.positive(
+            "lifecycle.design_life_years".to_string(),
+            lifecycle.design_life_years,
+        );
+        v.bounded(
+            "lifecycle.deorbit_days".to_string(),
+            lifecycle.deorbit_days,
+            0.0,
+            f64::MAX,
+        );
+
+        let conjunctions = &self.conjunctions;
+        v.positive(
+            "conjunctions.screening_radius_km".to_string(),
+            conjunctions.screening_radius_km,
+        );
+        v.bounded(
+            "conjunctions.warnings_per_collision".to_string(),
+            conjunctions.warnings_per_collision,
+            1.0,
+            f64::MAX,
+        );
+        v.bounded(
+            "conjunctions.maneuver_threshold_km".to_string(),
+            conjunctions.maneuver_threshold_km,
+            0.0,
+            conjunctions.screening_radius_km,
+        );
+
+        let drag = &self.drag;
+        v.positive("drag.drag_coefficient".to_string(), drag.drag_coefficient);
+        v.bounded(
+            "drag.reentry_altitude_km".to_string(),
+            drag.reentry_altitude_km,
+            0.0,
+            f64::MAX,
+        );
+        v.positive(
+            "drag.solar_cycle.period_years".to_string(),
+            drag.solar_cycle.period_years,
+        );
+        v.range("drag.solar_cycle.flux".to_string(), &drag.solar_cycle.flux);
+        v.positive(
+            "drag.solar_cycle.flux.min".to_string(),
+            drag.solar_cycle.flux.min,
+        );
+
+        let subsystems = &self.subsystems;
+        for (field, rate) in [
+            (
+                "propulsion_hazard_per_year",
+                subsystems.propulsion_hazard_per_year,
+            ),
+            ("comms_hazard_per_year", subsystems.comms_hazard_per_year),
+            ("power_hazard_per_year", subsystems.power_hazard_per_year),
+        ] {
+            v.bounded(format!("subsystems.{}", field), rate, 0.0, f64::MAX);
+        }
+
+        let fragmentation = &self.fragmentation;
+        v.positive(
+            "fragmentation.min_characteristic_length_m".to_string(),
+            fragmentation.min_characteristic_length_m,
+        );
+        v.bounded(
+            "fragmentation.altitude_spread_km".to_string(),
+            fragmentation.altitude_spread_km,
+            0.0,
+            f64::MAX,
+        );
+
+        let spillover = &self.spillover;
+        v.probability(
+            "spillover.neighbor_probability".to_string(),
+            spillover.neighbor_probability,
+        );
+        v.probability("spillover.damage_scale".to_string(), spillover.damage_scale);
+        for (i, link) in spillover.links.iter().enumerate() {
+            v.probability(
+                format!("spillover.links[{}].probability", i),
+                link.probability,
+            );
+        }
+
+        v.nonzero("reporting.report_interval", self.reporting.report_interval);
+        v.probability(
+            "reporting.ambiguity_level".to_string(),
+            self.reporting.ambiguity_level,
+        );
+    }
+}
+
+/// Path, within intervention `path`, of the value that made `field` invalid:
+/// `value` of a set_parameter (or a field below it), or the matching field of
+/// an added `tier`.
+fn intervention_field(
+    path: &str,
+    action: &InterventionAction,
+    tiers_before: usize,
+    field: &str,
+) -> String {
+    let (target, changed) = match action {
+        InterventionAction::SetParameter { field: set, .. } => {
+            (format!("{}.value", path), set.clone())
+        }
+        InterventionAction::AddTier { .. } => (
+            format!("{}.tier", path),
+            format!("capacity_tiers[{}]", tiers_before),
+        ),
+        InterventionAction::ForceDeorbit { .. } => return path.to_string(),
+    };
+    match field.strip_prefix(changed.as_str()) {
+        Some(rest) if rest.starts_with('.') || rest.starts_with('[') => {
+            format!("{}{}", target, rest)
+        }
+        _ => target,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_valid() {
+        assert!(SimulationConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_collects_field_paths() {
+        let mut config = SimulationConfig::default();
+        config.capacity_tiers[1].max_capacity = 0;
+        config.capacity_tiers[2].congestion_threshold = 1.0.into();
+        config.policy.launch_rate = f64::NAN.into();
+        config.reporting.report_interval = 0;
+
+        let errors = config.validate().unwrap_err().errors;
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "capacity_tiers[1].max_capacity",
+                "capacity_tiers[2].congestion_threshold",
+                "policy.launch_rate",
+                "reporting.report_interval",
+            ]
+        );
+        assert_eq!(errors[3].kind, ValidationErrorKind::Zero);
+    }
+
+    #[test]
+    fn test_schedule_bounds_are_checked() {
+        let mut config = SimulationConfig::default();
+        config.policy.launch_rate =
+            serde_json::from_str(r#"{"type": "piecewise", "keyframes": [[0, 0.02], [500, 1.5]]}"#)
+                .unwrap();
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ValidationErrorKind::NotAProbability(1.5));
+    }
+
+    #[test]
+    fn test_interventions_are_checked() {
+        let mut config = SimulationConfig {
+            interventions: serde_json::from_str(
+                r#"[
+                    {"at": 300, "action": "set_parameter", "field": "policy.launch_rate", "value": 3.0},
+                    {"at": 500, "action": "force_deorbit", "tier": "LEO-Lowest", "fraction": 0.2}
+                ]"#,
+            )
+            .unwrap(),
+            ..SimulationConfig::default()
+        };
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors[0].field, "interventions[0].value");
+
+        config.interventions[0].action = InterventionAction::SetParameter {
+            field: "policy.launch_rate".to_string(),
+            value: serde_json::json!(0.1),
+        };
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors[0].field, "interventions[1].tier");
+
+        config.time_steps = 400;
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(
+            errors[0],
+            ValidationError::new("interventions[1].at", ValidationErrorKind::AfterEnd(400))
+        );
+        config.time_steps = 1000;
+
+        config.interventions = serde_json::from_str(
+            r#"[
+                {"at": 100, "action": "add_tier", "tier": {"name": "LEO-Top", "max_capacity": 0,
+                    "congestion_threshold": 0.8, "failure_base_rate": 0.0005,
+                    "min_altitude_km": 1200.0, "max_altitude_km": 1500.0}},
+                {"at": 200, "action": "set_parameter", "field": "capacity_tiers[0]",
+                    "value": {"name": "LEO-Low", "max_capacity": 100, "congestion_threshold": 1.5,
+                        "failure_base_rate": 0.001, "min_altitude_km": 200.0, "max_altitude_km": 500.0}}
+            ]"#,
+        )
+        .unwrap();
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors[0].field, "interventions[0].tier.max_capacity");
+
+        config.interventions[0].action = InterventionAction::ForceDeorbit {
+            tier: None,
+            fraction: 0.5,
+            non_compliant_only: false,
+        };
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(
+            errors[0].field,
+            "interventions[1].value.congestion_threshold"
+        );
+    }
+
+    #[test]
+    fn test_overlapping_bands() {
+        let mut config = SimulationConfig::default();
+        config.capacity_tiers[2].min_altitude_km = Some(700.0);
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors[0].field, "capacity_tiers[2].min_altitude_km");
+        assert_eq!(
+            errors[0].kind,
+            ValidationErrorKind::OverlappingBand("LEO-Mid".to_string())
+        );
+    }
+
+    #[test]
+    fn test_duplicate_tier_names() {
+        let mut config = SimulationConfig::default();
+        config.capacity_tiers[2].name = config.capacity_tiers[0].name.clone();
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "capacity_tiers[2].name",
+                ValidationErrorKind::DuplicateName("LEO-Low".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn test_object_mix_fractions() {
+        let mut config = SimulationConfig::default();
+        config.object_mix.debris_fragment = 0.9;
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "object_mix");
+    }
+
+    #[test]
+    fn test_spillover_links_name_known_tiers() {
+        let mut config = SimulationConfig {
+            interventions: serde_json::from_str(
+                r#"[{"at": 100, "action": "add_tier", "tier": {
+                    "name": "MEO", "min_altitude_km": 2000.0, "max_altitude_km": 3000.0,
+                    "max_capacity": 100, "congestion_threshold": 0.8, "failure_base_rate": 0.0005
+                }}]"#,
+            )
+            .unwrap(),
+            ..SimulationConfig::default()
+        };
+        config.spillover.links = serde_json::from_str(
+            r#"[{"from": "LEO-Low", "to": "MEO", "probability": 0.1}, {"from": "GEO", "to": "LEO-Low", "probability": 2.0}]"#,
+        )
+        .unwrap();
+
+        let errors = config.validate().unwrap_err().errors;
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["spillover.links[1].probability", "spillover.links[1].from"]
+        );
+    }
+
+    #[test]
+    fn test_empty_tiers() {
+        let mut config = SimulationConfig::default();
+        config.capacity_tiers.clear();
+        let errors = config.validate().unwrap_err().errors;
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "capacity_tiers",
+                ValidationErrorKind::Empty
+            )]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::config::parameters::SimulationConfig;
+use crate::experiments::summary::RunSummary;
+use crate::experiments::sweep::{SweepRow, SweepSpec};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+/// Distribution of one metric across the runs of an ensemble.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricStats {
+    pub metric: String,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub p05: f64,
+    pub median: f64,
+    pub p95: f64,
+    pub max: f64,
+}
+
+/// Run the same configuration once per seed. An ensemble is a sweep without axes.
+pub fn run_ensemble(
+    base: &SimulationConfig,
+    seeds: &[u64],
+) -> Result<Vec<SweepRow>, Box<dyn Error>> {
+    if seeds.is_empty() {
+        return Err("ensemble needs at least one seed".into());
+    }
+    SweepSpec {
+        axes: Vec::new(),
+        seeds: seeds.to_vec(),
+    }
+    .run(base)
+}
+
+pub fn metric_stats(summaries: &[RunSummary]) -> Vec<MetricStats> {
+    RunSummary::METRICS
+        .iter()
+        .map(|&metric| {
+            let mut values: Vec<f64> = summaries.iter().filter_map(|s| s.metric(metric)).collect();
+            values.sort_by(|a, b| a.total_cmp(b));
+            let n = values.len();
+            let quantile = |q: f64| {
+                if n == 0 {
+                    f64::NAN
+                } else {
+                    values[((n - 1) as f64 * q).round() as usize]
+                }
+            };
+            let mean = values.iter().sum::<f64>() / n as f64;
+            let std_dev = if n < 2 {
+                0.0
+            } else {
+                (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+            };
+            MetricStats {
+                metric: metric.to_string(),
+                mean,
+                std_dev,
+                min: quantile(0.0),
+                p05: quantile(0.05),
+                median: quantile(0.5),
+                p95: quantile(0.95),
+                max: quantile(1.0),
+            }
+        })
+        .collect()
+}
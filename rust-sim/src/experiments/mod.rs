@@ -0,0 +1,4 @@
+pub mod ensemble;
+pub mod sensitivity;
+pub mod summary;
+pub mod sweep;
@@ -0,0 +1,522 @@
+use crate::config::loader::load_file;
+use crate::config::parameters::SimulationConfig;
+use crate::experiments::summary::RunSummary;
+use crate::simulation::engine::SimulationEngine;
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::error::Error;
+use std::path::Path;
+
+/// Global sensitivity analysis: which sampled parameters drive which outputs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivitySpec {
+    pub parameters: Vec<SensitivityParameter>,
+    pub method: SensitivityMethod,
+    /// Output metrics to analyse, by [`RunSummary::METRICS`] name.
+    #[serde(default = "default_metrics")]
+    pub metrics: Vec<String>,
+    /// Seed for the design and the bootstrap; independent of the engine seed.
+    #[serde(default = "default_sampling_seed")]
+    pub sampling_seed: u64,
+    /// Bootstrap resamples used for confidence intervals.
+    #[serde(default = "default_bootstrap")]
+    pub bootstrap: usize,
+    #[serde(default = "default_confidence_level")]
+    pub confidence_level: f64,
+}
+
+fn default_metrics() -> Vec<String> {
+    vec![
+        "cascading_failures".to_string(),
+        "final_ground_truth_risk".to_string(),
+    ]
+}
+
+fn default_sampling_seed() -> u64 {
+    0
+}
+
+fn default_bootstrap() -> usize {
+    200
+}
+
+fn default_confidence_level() -> f64 {
+    0.95
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensitivityParameter {
+    /// Dotted config path, e.g. `policy.launch_rate` or `capacity_tiers[*].failure_base_rate`.
+    pub field: String,
+    pub min: f64,
+    pub max: f64,
+    /// Round sampled values, for integer fields such as `max_capacity`.
+    #[serde(default)]
+    pub integer: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "design", rename_all = "snake_case")]
+pub enum SensitivityMethod {
+    /// Saltelli design with Sobol first-order and total-effect estimators.
+    /// Costs `samples * (k + 2)` runs for `k` parameters.
+    Sobol { samples: usize },
+    /// Morris elementary effects on a `levels`-point grid; `levels` must be
+    /// even. Costs `trajectories * (k + 1)` runs.
+    Morris { trajectories: usize, levels: usize },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SobolIndex {
+    pub metric: String,
+    pub parameter: String,
+    pub first_order: f64,
+    pub first_order_ci: (f64, f64),
+    pub total_effect: f64,
+    pub total_effect_ci: (f64, f64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MorrisIndex {
+    pub metric: String,
+    pub parameter: String,
+    pub mu: f64,
+    pub mu_star: f64,
+    pub mu_star_ci: (f64, f64),
+    pub sigma: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "indices", rename_all = "snake_case")]
+pub enum SensitivityReport {
+    Sobol(Vec<SobolIndex>),
+    Morris(Vec<MorrisIndex>),
+}
+
+impl SensitivitySpec {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        load_file(path.as_ref())
+    }
+
+    pub fn run_count(&self) -> usize {
+        let k = self.parameters.len();
+        match self.method {
+            SensitivityMethod::Sobol { samples } => samples * (k + 2),
+            SensitivityMethod::Morris { trajectories, .. } => trajectories * (k + 1),
+        }
+    }
+
+    /// Sample the design, run the engine at every point and compute indices.
+    pub fn run(&self, base: &SimulationConfig) -> Result<SensitivityReport, Box<dyn Error>> {
+        self.check()?;
+        let mut rng = SimulationRng::new(Some(self.sampling_seed));
+        let k = self.parameters.len();
+
+        let evaluate = |unit: &[f64]| -> Result<Vec<f64>, Box<dyn Error>> {
+            let config = self.config_at(base, unit)?;
+            let results = SimulationEngine::new(config)?.run();
+            let summary = RunSummary::from_results(&results);
+            Ok(self
+                .metrics
+                .iter()
+                .map(|m| summary.metric(m).unwrap_or(f64::NAN))
+                .collect())
+        };
+
+        match self.method {
+            SensitivityMethod::Sobol { samples } => {
+                let estimates = sobol(k, samples, &mut rng, evaluate)?;
+                let mut indices = Vec::new();
+                for (m, metric) in self.metrics.iter().enumerate() {
+                    for (i, param) in self.parameters.iter().enumerate() {
+                        let (s1, st) = estimates.indices(m, i);
+                        indices.push(SobolIndex {
+                            metric: metric.clone(),
+                            parameter: param.field.clone(),
+                            first_order: s1,
+                            first_order_ci: estimates.bootstrap(
+                                m,
+                                i,
+                                self.bootstrap,
+                                self.confidence_level,
+                                &mut rng,
+                                |s| s.0,
+                            ),
+                            total_effect: st,
+                            total_effect_ci: estimates.bootstrap(
+                                m,
+                                i,
+                                self.bootstrap,
+                                self.confidence_level,
+                                &mut rng,
+                                |s| s.1,
+                            ),
+                        });
+                    }
+                }
+                Ok(SensitivityReport::Sobol(indices))
+            }
+            SensitivityMethod::Morris {
+                trajectories,
+                levels,
+            } => {
+                let effects = morris(k, trajectories, levels, &mut rng, evaluate)?;
+                let mut indices = Vec::new();
+                for (m, metric) in self.metrics.iter().enumerate() {
+                    for (i, param) in self.parameters.iter().enumerate() {
+                        let ee: Vec<f64> = effects.iter().map(|t| t[i][m]).collect();
+                        let abs: Vec<f64> = ee.iter().map(|e| e.abs()).collect();
+                        indices.push(MorrisIndex {
+                            metric: metric.clone(),
+                            parameter: param.field.clone(),
+                            mu: mean(&ee),
+                            mu_star: mean(&abs),
+                            mu_star_ci: bootstrap_mean(
+                                &abs,
+                                self.bootstrap,
+                                self.confidence_level,
+                                &mut rng,
+                            ),
+                            sigma: std_dev(&ee),
+                        });
+                    }
+                }
+                Ok(SensitivityReport::Morris(indices))
+            }
+        }
+    }
+
+    fn check(&self) -> Result<(), Box<dyn Error>> {
+        if self.parameters.is_empty() {
+            return Err("sensitivity spec has no parameters".into());
+        }
+        for p in &self.parameters {
+            if !(p.min.is_finite() && p.max.is_finite() && p.min < p.max) {
+                return Err(format!("parameter '{}' needs finite min < max", p.field).into());
+            }
+        }
+        for m in &self.metrics {
+            if !RunSummary::METRICS.contains(&m.as_str()) {
+                return Err(format!("unknown metric '{}'", m).into());
+            }
+        }
+        match self.method {
+            SensitivityMethod::Sobol { samples } if samples < 2 => {
+                Err("sobol design needs at least 2 samples".into())
+            }
+            SensitivityMethod::Morris {
+                trajectories,
+                levels,
+            } if trajectories < 1 || levels < 2 => {
+                Err("morris design needs at least 1 trajectory and 2 levels".into())
+            }
+            // The step is levels / (2 (levels - 1)) of the range, which only
+            // lands back on the grid, and inside it, for an even number of levels.
+            SensitivityMethod::Morris { levels, .. } if levels % 2 != 0 => {
+                Err(format!("method.levels must be even, got {}", levels).into())
+            }
+            _ if !(self.confidence_level > 0.0 && self.confidence_level < 1.0) => {
+                Err("confidence_level must be strictly between 0 and 1".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Map a point of the unit hypercube onto the configured parameter ranges.
+    fn config_at(
+        &self,
+        base: &SimulationConfig,
+        unit: &[f64],
+    ) -> Result<SimulationConfig, Box<dyn Error>> {
+        let mut config = base.clone();
+        for (param, u) in self.parameters.iter().zip(unit) {
+            let x = param.min + (param.max - param.min) * u;
+            let value = if param.integer {
+                Value::from(x.round() as i64)
+            } else {
+                Value::from(x)
+            };
+            config.set_path(&param.field, value)?;
+        }
+        Ok(config)
+    }
+}
+
+/// Model outputs on a Saltelli design: `f_a[j]`, `f_b[j]` and `f_ab[i][j]`, each
+/// a vector of metrics.
+struct SobolEstimates {
+    f_a: Vec<Vec<f64>>,
+    f_b: Vec<Vec<f64>>,
+    f_ab: Vec<Vec<Vec<f64>>>,
+}
+
+impl SobolEstimates {
+    fn indices(&self, metric: usize, param: usize) -> (f64, f64) {
+        let rows: Vec<usize> = (0..self.f_a.len()).collect();
+        self.indices_on(metric, param, &rows)
+    }
+
+    /// Saltelli (2010) first-order and Jansen total-effect estimators over the
+    /// given sample rows.
+    fn indices_on(&self, metric: usize, param: usize, rows: &[usize]) -> (f64, f64) {
+        let n = rows.len() as f64;
+        let outputs: Vec<f64> = rows
+            .iter()
+            .flat_map(|&j| [self.f_a[j][metric], self.f_b[j][metric]])
+            .collect();
+        let var = variance(&outputs);
+        if var <= 0.0 || !var.is_finite() {
+            return (0.0, 0.0);
+        }
+
+        let mut first = 0.0;
+        let mut total = 0.0;
+        for &j in rows {
+            let a = self.f_a[j][metric];
+            let b = self.f_b[j][metric];
+            let ab = self.f_ab[param][j][metric];
+            first += b * (ab - a);
+            total += (a - ab).powi(2);
+        }
+        (first / n / var, 0.5 * total / n / var)
+    }
+
+    fn bootstrap(
+        &self,
+        metric: usize,
+        param: usize,
+        resamples: usize,
+        level: f64,
+        rng: &mut SimulationRng,
+        pick: fn((f64, f64)) -> f64,
+    ) -> (f64, f64) {
+        let n = self.f_a.len();
+        let estimates: Vec<f64> = (0..resamples)
+            .map(|_| {
+                let rows: Vec<usize> = (0..n).map(|_| rng.gen_range(0..n)).collect();
+                pick(self.indices_on(metric, param, &rows))
+            })
+            .collect();
+        percentile_interval(estimates, level)
+    }
+}
+
+fn sobol<F>(
+    k: usize,
+    n: usize,
+    rng: &mut SimulationRng,
+    mut evaluate: F,
+) -> Result<SobolEstimates, Box<dyn Error>>
+where
+    F: FnMut(&[f64]) -> Result<Vec<f64>, Box<dyn Error>>,
+{
+    let a: Vec<Vec<f64>> = (0..n).map(|_| unit_point(k, rng)).collect();
+    let b: Vec<Vec<f64>> = (0..n).map(|_| unit_point(k, rng)).collect();
+
+    let f_a = a
+        .iter()
+        .map(|x| evaluate(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let f_b = b
+        .iter()
+        .map(|x| evaluate(x))
+        .collect::<Result<Vec<_>, _>>()?;
+    let f_ab = (0..k)
+        .map(|i| {
+            a.iter()
+                .zip(&b)
+                .map(|(xa, xb)| {
+                    let mut x = xa.clone();
+                    x[i] = xb[i];
+                    evaluate(&x)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SobolEstimates { f_a, f_b, f_ab })
+}
+
+/// Elementary effects per trajectory, indexed `[trajectory][param][metric]`,
+/// in units of the normalized parameter range.
+fn morris<F>(
+    k: usize,
+    trajectories: usize,
+    levels: usize,
+    rng: &mut SimulationRng,
+    mut evaluate: F,
+) -> Result<Vec<Vec<Vec<f64>>>, Box<dyn Error>>
+where
+    F: FnMut(&[f64]) -> Result<Vec<f64>, Box<dyn Error>>,
+{
+    let step = 1.0 / (levels - 1) as f64;
+    let delta = levels as f64 / (2.0 * (levels - 1) as f64);
+
+    let mut effects = Vec::with_capacity(trajectories);
+    for _ in 0..trajectories {
+        let mut x: Vec<f64> = (0..k)
+            .map(|_| rng.gen_range(0..levels) as f64 * step)
+            .collect();
+        let mut order: Vec<usize> = (0..k).collect();
+        for i in (1..k).rev() {
+            order.swap(i, rng.gen_range(0..=i));
+        }
+
+        let mut f_prev = evaluate(&x)?;
+        let mut trajectory = vec![Vec::new(); k];
+        for &i in &order {
+            let signed = if x[i] + delta <= 1.0 + 1e-12 {
+                delta
+            } else {
+                -delta
+            };
+            x[i] += signed;
+            let f_next = evaluate(&x)?;
+            trajectory[i] = f_next
+                .iter()
+                .zip(&f_prev)
+                .map(|(next, prev)| (next - prev) / signed)
+                .collect();
+            f_prev = f_next;
+        }
+        effects.push(trajectory);
+    }
+    Ok(effects)
+}
+
+fn unit_point(k: usize, rng: &mut SimulationRng) -> Vec<f64> {
+    (0..k).map(|_| rng.gen_range(0.0..1.0)).collect()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn variance(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let m = mean(values);
+    values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    variance(values).sqrt()
+}
+
+fn bootstrap_mean(
+    values: &[f64],
+    resamples: usize,
+    level: f64,
+    rng: &mut SimulationRng,
+) -> (f64, f64) {
+    let n = values.len();
+    let estimates: Vec<f64> = (0..resamples)
+        .map(|_| (0..n).map(|_| values[rng.gen_range(0..n)]).sum::<f64>() / n as f64)
+        .collect();
+    percentile_interval(estimates, level)
+}
+
+fn percentile_interval(mut estimates: Vec<f64>, level: f64) -> (f64, f64) {
+    estimates.retain(|e| e.is_finite());
+    if estimates.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+    estimates.sort_by(|a, b| a.total_cmp(b));
+    let tail = (1.0 - level) / 2.0;
+    let at = |q: f64| estimates[((estimates.len() - 1) as f64 * q).round() as usize];
+    (at(tail), at(1.0 - tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // f = 4 x0 + x1 with x uniform on [0, 1]: S0 = 16/17, S1 = 1/17, x2 is inert.
+    fn linear(x: &[f64]) -> Result<Vec<f64>, Box<dyn Error>> {
+        Ok(vec![4.0 * x[0] + x[1]])
+    }
+
+    #[test]
+    fn test_sobol_linear_model() {
+        let mut rng = SimulationRng::new(Some(7));
+        let estimates = sobol(3, 4096, &mut rng, linear).unwrap();
+
+        let (s0, t0) = estimates.indices(0, 0);
+        let (s1, t1) = estimates.indices(0, 1);
+        let (s2, t2) = estimates.indices(0, 2);
+        assert!((s0 - 16.0 / 17.0).abs() < 0.05, "s0 = {}", s0);
+        assert!((t0 - 16.0 / 17.0).abs() < 0.05, "t0 = {}", t0);
+        assert!((s1 - 1.0 / 17.0).abs() < 0.05, "s1 = {}", s1);
+        assert!((t1 - 1.0 / 17.0).abs() < 0.05, "t1 = {}", t1);
+        assert!(s2.abs() < 0.02 && t2.abs() < 0.02);
+
+        let (lo, hi) = estimates.bootstrap(0, 0, 100, 0.95, &mut rng, |s| s.0);
+        assert!(lo <= s0 && s0 <= hi);
+    }
+
+    #[test]
+    fn test_morris_linear_model() {
+        let mut rng = SimulationRng::new(Some(7));
+        let effects = morris(3, 10, 4, &mut rng, linear).unwrap();
+        for trajectory in &effects {
+            assert!((trajectory[0][0] - 4.0).abs() < 1e-9);
+            assert!((trajectory[1][0] - 1.0).abs() < 1e-9);
+            assert!(trajectory[2][0].abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_morris_points_stay_in_range() {
+        let parameter = |field: &str, min, max, integer| SensitivityParameter {
+            field: field.to_string(),
+            min,
+            max,
+            integer,
+        };
+        let mut spec = SensitivitySpec {
+            parameters: vec![
+                parameter("policy.launch_rate", 0.5, 2.0, false),
+                parameter("capacity_tiers[*].max_capacity", 100.0, 400.0, true),
+            ],
+            method: SensitivityMethod::Morris {
+                trajectories: 20,
+                levels: 3,
+            },
+            metrics: default_metrics(),
+            sampling_seed: 0,
+            bootstrap: 10,
+            confidence_level: 0.95,
+        };
+        assert!(spec
+            .check()
+            .unwrap_err()
+            .to_string()
+            .contains("method.levels"));
+
+        for levels in [2, 4, 6] {
+            spec.method = SensitivityMethod::Morris {
+                trajectories: 20,
+                levels,
+            };
+            spec.check().unwrap();
+
+            let base = SimulationConfig::default();
+            let mut rng = SimulationRng::new(Some(7));
+            morris(2, 20, levels, &mut rng, |unit| {
+                let config = spec.config_at(&base, unit)?;
+                let launch_rate = config.policy.launch_rate.value_at(0);
+                assert!((0.5..=2.0).contains(&launch_rate), "{}", launch_rate);
+                for tier in &config.capacity_tiers {
+                    assert!((100..=400).contains(&tier.max_capacity));
+                }
+                Ok(vec![0.0])
+            })
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::models::event::EventType;
+use crate::simulation::engine::SimulationResults;
+use serde::{Deserialize, Serialize};
+
+/// Scalar outcome metrics of one run, used to compare runs side by side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub final_agent_count: usize,
+    pub cascading_failures: usize,
+    pub collisions: usize,
+    pub launches: usize,
+    pub deorbits: usize,
+    pub natural_reentries: usize,
+    pub migrations: usize,
+    #[serde(default)]
+    pub subsystem_failures: usize,
+    #[serde(default)]
+    pub avoidance_maneuvers: usize,
+    #[serde(default)]
+    pub compliance_violations: usize,
+    #[serde(default)]
+    pub spillovers: usize,
+    pub fragments_created: usize,
+    pub total_events: usize,
+    pub mean_ground_truth_risk: f64,
+    pub final_ground_truth_risk: f64,
+    pub final_utilization: f64,
+    pub peak_utilization: f64,
+}
+
+impl RunSummary {
+    pub const METRICS: [&'static str; 17] = [
+        "final_agent_count",
+        "cascading_failures",
+        "collisions",
+        "launches",
+        "deorbits",
+        "natural_reentries",
+        "migrations",
+        "subsystem_failures",
+        "avoidance_maneuvers",
+        "compliance_violations",
+        "spillovers",
+        "fragments_created",
+        "total_events",
+        "mean_ground_truth_risk",
+        "final_ground_truth_risk",
+        "final_utilization",
+        "peak_utilization",
+    ];
+
+    pub fn from_results(results: &SimulationResults) -> Self {
+        let count = |pred: fn(&EventType) -> bool| {
+            results
+                .events
+                .iter()
+                .filter(|e| pred(&e.event_type))
+                .count()
+        };
+
+        let risks: Vec<f64> = results
+            .reports
+            .iter()
+            .map(|r| r.ground_truth_risk)
+            .collect();
+        let mean_ground_truth_risk = if risks.is_empty() {
+            0.0
+        } else {
+            risks.iter().sum::<f64>() / risks.len() as f64
+        };
+        let last = results.reports.last();
+
+        Self {
+            final_agent_count: results.final_agent_count,
+            cascading_failures: results.cascading_failures,
+            collisions: count(|t| matches!(t, EventType::Collision { .. })),
+            launches: count(|t| matches!(t, EventType::Launch { .. })),
+            deorbits: count(|t| matches!(t, EventType::Deorbit { .. })),
+            natural_reentries: count(|t| matches!(t, EventType::NaturalReentry { .. })),
+            migrations: count(|t| matches!(t, EventType::Migration { .. })),
+            subsystem_failures: count(|t| matches!(t, EventType::SubsystemFailure { .. })),
+            avoidance_maneuvers: count(|t| matches!(t, EventType::AvoidanceManeuver { .. })),
+            compliance_violations: count(|t| matches!(t, EventType::ComplianceViolation { .. })),
+            spillovers: count(|t| matches!(t, EventType::Spillover { .. })),
+            fragments_created: results
+                .events
+                .iter()
+                .map(|e| match e.event_type {
+                    EventType::Fragmentation { fragments, .. } => fragments,
+                    _ => 0,
+                })
+                .sum(),
+            total_events: results.events.len(),
+            mean_ground_truth_risk,
+            final_ground_truth_risk: last.map_or(0.0, |r| r.ground_truth_risk),
+            final_utilization: last.map_or(0.0, |r| r.ground_truth_utilization),
+            peak_utilization: results
+                .reports
+                .iter()
+                .map(|r| r.ground_truth_utilization)
+                .fold(0.0, f64::max),
+        }
+    }
+
+    /// Look up a metric by its name in [`RunSummary::METRICS`].
+    pub fn metric(&self, name: &str) -> Option<f64> {
+        let value = match name {
+            "final_agent_count" => self.final_agent_count as f64,
+            "cascading_failures" => self.cascading_failures as f64,
+            "collisions" => self.collisions as f64,
+            "launches" => self.launches as f64,
+            "deorbits" => self.deorbits as f64,
+            "natural_reentries" => self.natural_reentries as f64,
+            "migrations" => self.migrations as f64,
+            "subsystem_failures" => self.subsystem_failures as f64,
+            "avoidance_maneuvers" => self.avoidance_maneuvers as f64,
+            "compliance_violations" => self.compliance_violations as f64,
+            "spillovers" => self.spillovers as f64,
+            "fragments_created" => self.fragments_created as f64,
+            "total_events" => self.total_events as f64,
+            "mean_ground_truth_risk" => self.mean_ground_truth_risk,
+            "final_ground_truth_risk" => self.final_ground_truth_risk,
+            "final_utilization" => self.final_utilization,
+            "peak_utilization" => self.peak_utilization,
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Metric values formatted for a CSV row, in [`RunSummary::METRICS`] order.
+    pub fn to_record(&self) -> Vec<String> {
+        vec![
+            self.final_agent_count.to_string(),
+            self.cascading_failures.to_string(),
+            self.collisions.to_string(),
+            self.launches.to_string(),
+            self.deorbits.to_string(),
+            self.natural_reentries.to_string(),
+            self.migrations.to_string(),
+            self.subsystem_failures.to_string(),
+            self.avoidance_maneuvers.to_string(),
+            self.compliance_violations.to_string(),
+            self.spillovers.to_string(),
+            self.fragments_created.to_string(),
+            self.total_events.to_string(),
+            format!("{:.6}", self.mean_ground_truth_risk),
+            format!("{:.6}", self.final_ground_truth_risk),
+            format!("{:.6}", self.final_utilization),
+            format!("{:.6}", self.peak_utilization),
+        ]
+    }
+}
@@ -0,0 +1,204 @@
+use crate::config::loader::load_file;
+use crate::config::parameters::SimulationConfig;
+use crate::experiments::summary::RunSummary;
+use crate::simulation::engine::SimulationEngine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::error::Error;
+use std::path::Path;
+
+/// A grid of config variations: the cartesian product of all axes, run once
+/// per seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepSpec {
+    pub axes: Vec<SweepAxis>,
+    /// Seeds to run every grid point with. Empty means the base config's seed.
+    #[serde(default)]
+    pub seeds: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepAxis {
+    /// Dotted config path, e.g. `policy.launch_rate` or `capacity_tiers[*].failure_base_rate`.
+    pub field: String,
+    #[serde(flatten)]
+    pub values: AxisValues,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AxisValues {
+    /// Explicit list of values.
+    List { values: Vec<Value> },
+    /// `steps` evenly spaced values from `start` to `end`, inclusive.
+    Range { start: f64, end: f64, steps: usize },
+}
+
+impl AxisValues {
+    pub fn expand(&self) -> Vec<Value> {
+        match self {
+            AxisValues::List { values } => values.clone(),
+            AxisValues::Range { start, end, steps } => {
+                let n = *steps;
+                (0..n)
+                    .map(|i| {
+                        let x = if n == 1 {
+                            *start
+                        } else {
+                            start + (end - start) * i as f64 / (n - 1) as f64
+                        };
+                        number_value(x)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Integral values stay integers so range axes can drive `usize` fields.
+fn number_value(x: f64) -> Value {
+    if x.fract() == 0.0 && x.abs() < 1e15 {
+        Value::from(x as i64)
+    } else {
+        Value::from(x)
+    }
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SweepRun {
+    pub index: usize,
+    pub seed: Option<u64>,
+    pub params: Vec<(String, Value)>,
+    pub config: SimulationConfig,
+}
+
+#[derive(Debug, Clone)]
+pub struct SweepRow {
+    pub run: SweepRun,
+    pub summary: RunSummary,
+}
+
+impl SweepSpec {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        load_file(path.as_ref())
+    }
+
+    /// Expand the grid into fully resolved configs, one per (grid point, seed).
+    pub fn expand(&self, base: &SimulationConfig) -> Result<Vec<SweepRun>, Box<dyn Error>> {
+        let mut points: Vec<Vec<(String, Value)>> = vec![Vec::new()];
+        for axis in &self.axes {
+            let values = axis.values.expand();
+            if values.is_empty() {
+                return Err(format!("sweep axis '{}' has no values", axis.field).into());
+            }
+            points = points
+                .into_iter()
+                .flat_map(|point| {
+                    values.iter().map(move |v| {
+                        let mut next = point.clone();
+                        next.push((axis.field.clone(), v.clone()));
+                        next
+                    })
+                })
+                .collect();
+        }
+
+        let seeds: Vec<Option<u64>> = if self.seeds.is_empty() {
+            vec![base.random_seed]
+        } else {
+            self.seeds.iter().copied().map(Some).collect()
+        };
+
+        let mut runs = Vec::with_capacity(points.len() * seeds.len());
+        for params in &points {
+            let mut config = base.clone();
+            for (field, value) in params {
+                config.set_path(field, value.clone())?;
+            }
+            for &seed in &seeds {
+                let mut config = config.clone();
+                config.random_seed = seed;
+                runs.push(SweepRun {
+                    index: runs.len(),
+                    seed,
+                    params: params.clone(),
+                    config,
+                });
+            }
+        }
+        Ok(runs)
+    }
+
+    /// Expand and run the whole grid sequentially.
+    pub fn run(&self, base: &SimulationConfig) -> Result<Vec<SweepRow>, Box<dyn Error>> {
+        self.expand(base)?
+            .into_iter()
+            .map(|run| {
+                let results = SimulationEngine::new(run.config.clone())
+                    .map_err(|e| format!("sweep run {}: {}", run.index, e))?
+                    .run();
+                Ok(SweepRow {
+                    summary: RunSummary::from_results(&results),
+                    run,
+                })
+            })
+            .collect()
+    }
+
+    pub fn fields(&self) -> Vec<&str> {
+        self.axes.iter().map(|a| a.field.as_str()).collect()
+    }
+}
+
+impl SweepRow {
+    pub fn param_values(&self) -> Vec<String> {
+        self.run
+            .params
+            .iter()
+            .map(|(_, v)| format_value(v))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_range_expansion() {
+        let values = AxisValues::Range {
+            start: 0.0,
+            end: 1.0,
+            steps: 3,
+        }
+        .expand();
+        assert_eq!(values, vec![json!(0), json!(0.5), json!(1)]);
+    }
+
+    #[test]
+    fn test_grid_expansion() {
+        let spec: SweepSpec = serde_json::from_value(json!({
+            "axes": [
+                { "field": "policy.mitigation_strength", "values": [0.1, 0.5] },
+                { "field": "capacity_tiers[0].max_capacity", "start": 100, "end": 300, "steps": 3 }
+            ],
+            "seeds": [1, 2]
+        }))
+        .unwrap();
+
+        let runs = spec.expand(&SimulationConfig::default()).unwrap();
+        assert_eq!(runs.len(), 2 * 3 * 2);
+        let last = runs.last().unwrap();
+        assert_eq!(last.seed, Some(2));
+        assert_eq!(last.config.policy.mitigation_strength, 0.5.into());
+        assert_eq!(last.config.capacity_tiers[0].max_capacity, 300);
+    }
+}
@@ -1,9 +1,10 @@
-pub mod config;
-pub mod models;
-pub mod reporting;
-pub mod simulation;
-pub mod utils;
-
-// Re-export commonly used types
-pub use models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
-pub use simulation::engine::{SimulationEngine, SimulationResults};
\ No newline at end of file
+pub mod config;
+pub mod experiments;
+pub mod models;
+pub mod reporting;
+pub mod simulation;
+pub mod utils;
+
+// Re-export commonly used types
+pub use models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
+pub use simulation::engine::{SimulationEngine, SimulationResults};
@@ -1,41 +1,828 @@
-use autonomous_infrastructure_risk::config::parameters::SimulationConfig;
-use autonomous_infrastructure_risk::simulation::engine::SimulationEngine;
-use autonomous_infrastructure_risk::utils::export::export_to_csv;
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("Autonomous Infrastructure Risk Simulation");
-    println!("================================================\n");
-
-    // Load configuration
-    let config = SimulationConfig::default();
-    println!("Configuration loaded:");
-    println!("  Time steps: {}", config.time_steps);
-    println!("  Initial agents: {}", config.initial_agents);
-    println!("  Capacity tiers: {}\n", config.capacity_tiers.len());
-
-    // Initialize simulation
-    println!("Initializing simulation engine...");
-    let mut engine = SimulationEngine::new(config);
-
-    // Run simulation
-    println!("Running simulation...\n");
-    let results = engine.run();
-
-    println!("\nSimulation complete!");
-    println!("  Total reports generated: {}", results.reports.len());
-    println!("  Final agent count: {}", results.final_agent_count);
-    println!("  Cascading failures: {}", results.cascading_failures);
-
-    // Export results
-    let output_path = "simulation_outputs.csv";
-    println!("\nExporting to {}...", output_path);
-    export_to_csv(&results, output_path)?;
-
-    println!("Export complete!\n");
-    println!("Next steps:");
-    println!("  1. Open Jupyter Lab: jupyter lab");
-    println!("  2. Navigate to notebooks/01_simulation_overview.ipynb");
-    println!("  3. Run the analysis pipeline\n");
-
-    Ok(())
-}
\ No newline at end of file
+use autonomous_infrastructure_risk::config::layering::parse_assignment;
+use autonomous_infrastructure_risk::config::parameters::{ReportingConfig, SimulationConfig};
+use autonomous_infrastructure_risk::config::scenarios::SCENARIOS;
+use autonomous_infrastructure_risk::config::schema::simulation_config_schema;
+use autonomous_infrastructure_risk::experiments::ensemble::{metric_stats, run_ensemble};
+use autonomous_infrastructure_risk::experiments::sensitivity::{
+    SensitivityReport, SensitivitySpec,
+};
+use autonomous_infrastructure_risk::experiments::summary::RunSummary;
+use autonomous_infrastructure_risk::experiments::sweep::SweepSpec;
+use autonomous_infrastructure_risk::models::event::Event;
+use autonomous_infrastructure_risk::reporting::causality::EventGraph;
+use autonomous_infrastructure_risk::reporting::replay::replay_reports;
+use autonomous_infrastructure_risk::simulation::engine::{SimulationEngine, StatusReport};
+use autonomous_infrastructure_risk::utils::export::{
+    export_capacity_to_csv, export_event_graph_to_dot, export_event_graph_to_graphml,
+    export_operators_to_csv, export_reports_to_csv, export_sensitivity_to_csv, export_sweep_to_csv,
+    export_to_csv, export_to_json, export_to_jsonl,
+};
+use autonomous_infrastructure_risk::utils::import::{import_json, import_jsonl};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Runtime or I/O failure.
+const EXIT_FAILURE: u8 = 1;
+// Exit code 2 is reserved for usage errors, which clap reports itself.
+/// A config or spec file could not be loaded, or failed validation.
+const EXIT_INVALID_CONFIG: u8 = 3;
+
+const SUMMARY_FILE: &str = "summary.json";
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "simulator",
+    version,
+    about = "Infrastructure simulation with autonomous language reporting"
+)]
+struct Cli {
+    /// Print a machine-readable JSON summary on stdout instead of progress text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a single scenario
+    Run {
+        #[command(flatten)]
+        config: ConfigArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        /// Also write the report CSV to this file
+        #[arg(short = 'o', long = "output", value_name = "CSV")]
+        report_csv: Option<PathBuf>,
+    },
+    /// Run a parameter grid described by a sweep spec
+    Sweep {
+        /// Sweep spec (.toml or .json)
+        spec: PathBuf,
+        #[command(flatten)]
+        config: ConfigArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Run one scenario across many seeds and summarize the spread of outcomes
+    Ensemble {
+        #[command(flatten)]
+        config: ConfigArgs,
+        /// Number of runs
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// First seed; runs use consecutive seeds (defaults to the config seed, or 0)
+        #[arg(long)]
+        first_seed: Option<u64>,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Compute Sobol or Morris sensitivity indices described by a spec
+    Sensitivity {
+        /// Sensitivity spec (.toml or .json)
+        spec: PathBuf,
+        #[command(flatten)]
+        config: ConfigArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Regenerate report text from the event log of a saved run
+    Replay {
+        /// Output directory of a previous `run`
+        dir: PathBuf,
+        /// Override hedging in the regenerated reports
+        #[arg(long)]
+        hedging: Option<bool>,
+        /// Override confidence modulation in the regenerated reports
+        #[arg(long)]
+        confidence_modulation: Option<bool>,
+        /// Output CSV path (defaults to reports_replayed.csv in the run directory)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Export the causal chains in the event log of a saved run
+    Graph {
+        /// Output directory of a previous `run`
+        dir: PathBuf,
+        /// Graph file format
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// Output path (defaults to event_graph.dot or event_graph.graphml in the run directory)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Summarize an output directory
+    Inspect {
+        /// Output directory written by any other subcommand
+        dir: PathBuf,
+    },
+    /// List the built-in scenario presets
+    Scenarios,
+    /// Print the JSON Schema for scenario files
+    Schema {
+        /// Write the schema to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum GraphFormat {
+    Dot,
+    Graphml,
+}
+
+/// Layers are applied in order: base (`--config`, `--scenario` or the
+/// built-in default), each `--overlay`, `--seed`/`--steps`, then each `--set`.
+#[derive(Args, Debug)]
+struct ConfigArgs {
+    /// Base scenario file (.toml or .json); defaults to the built-in configuration
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Built-in scenario preset (see `simulator scenarios`)
+    #[arg(long, conflicts_with = "config")]
+    scenario: Option<String>,
+
+    /// Override the random seed
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Override the number of time steps
+    #[arg(long)]
+    steps: Option<usize>,
+
+    /// Partial scenario file merged on top of the base (repeatable)
+    #[arg(long = "overlay", value_name = "FILE")]
+    overlays: Vec<PathBuf>,
+
+    /// Override one field by dotted path, e.g. `capacity_tiers[1].max_capacity=400` (repeatable)
+    #[arg(long = "set", value_name = "PATH=VALUE")]
+    sets: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+struct OutputArgs {
+    /// Directory to write outputs into (created if missing)
+    #[arg(short = 'd', long = "out-dir", default_value = "simulation_output")]
+    out_dir: PathBuf,
+}
+
+/// Written as `summary.json` in every output directory and printed with `--json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct CommandSummary {
+    command: String,
+    status: String,
+    created_at: String,
+    output_dir: Option<String>,
+    files: Vec<String>,
+    details: Value,
+}
+
+impl CommandSummary {
+    fn new(command: &str, output_dir: Option<&Path>, files: Vec<String>, details: Value) -> Self {
+        Self {
+            command: command.to_string(),
+            status: "ok".to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            output_dir: output_dir.map(|d| d.to_string_lossy().into_owned()),
+            files,
+            details,
+        }
+    }
+}
+
+struct CliError {
+    code: u8,
+    message: String,
+}
+
+impl CliError {
+    fn config(e: impl Display) -> Self {
+        Self {
+            code: EXIT_INVALID_CONFIG,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<Box<dyn std::error::Error>> for CliError {
+    fn from(e: Box<dyn std::error::Error>) -> Self {
+        Self {
+            code: EXIT_FAILURE,
+            message: e.to_string(),
+        }
+    }
+}
+
+type CliResult<T> = Result<T, CliError>;
+
+/// Progress output, silenced when a JSON summary is requested.
+struct Ui {
+    json: bool,
+}
+
+impl Ui {
+    fn say(&self, message: impl Display) {
+        // A closed pipe (e.g. `| head`) should not abort a long run
+        if !self.json {
+            let _ = writeln!(std::io::stdout(), "{}", message);
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let ui = Ui { json: cli.json };
+
+    match dispatch(cli.command, &ui) {
+        Ok(summary) => {
+            if ui.json {
+                let _ = writeln!(
+                    std::io::stdout(),
+                    "{}",
+                    serde_json::to_string_pretty(&summary).unwrap_or_default()
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            if ui.json {
+                let error = json!({ "status": "error", "exit_code": e.code, "error": e.message });
+                let _ = writeln!(
+                    std::io::stdout(),
+                    "{}",
+                    serde_json::to_string_pretty(&error).unwrap_or_default()
+                );
+            } else {
+                eprintln!("error: {}", e.message);
+            }
+            ExitCode::from(e.code)
+        }
+    }
+}
+
+fn dispatch(command: Command, ui: &Ui) -> CliResult<CommandSummary> {
+    match command {
+        Command::Run {
+            config,
+            output,
+            report_csv,
+        } => run(&config, &output, report_csv.as_deref(), ui),
+        Command::Sweep {
+            spec,
+            config,
+            output,
+        } => sweep(&spec, &config, &output, ui),
+        Command::Ensemble {
+            config,
+            runs,
+            first_seed,
+            output,
+        } => ensemble(&config, runs, first_seed, &output, ui),
+        Command::Sensitivity {
+            spec,
+            config,
+            output,
+        } => sensitivity(&spec, &config, &output, ui),
+        Command::Replay {
+            dir,
+            hedging,
+            confidence_modulation,
+            output,
+        } => replay(&dir, hedging, confidence_modulation, output, ui),
+        Command::Graph {
+            dir,
+            format,
+            output,
+        } => graph(&dir, format, output, ui),
+        Command::Inspect { dir } => inspect(&dir, ui),
+        Command::Scenarios => scenarios(ui),
+        Command::Schema { output } => schema(output, ui),
+    }
+}
+
+fn resolve_config(args: &ConfigArgs, ui: &Ui) -> CliResult<SimulationConfig> {
+    let mut config = match (&args.config, &args.scenario) {
+        (Some(path), _) => SimulationConfig::from_file(path).map_err(CliError::config)?,
+        (None, Some(name)) => SimulationConfig::preset(name).ok_or_else(|| {
+            CliError::config(format!(
+                "unknown scenario '{}' (see `simulator scenarios`)",
+                name
+            ))
+        })?,
+        (None, None) => SimulationConfig::default(),
+    };
+    for overlay in &args.overlays {
+        config = config
+            .with_overlay_file(overlay)
+            .map_err(CliError::config)?;
+    }
+    if let Some(seed) = args.seed {
+        config.random_seed = Some(seed);
+    }
+    if let Some(steps) = args.steps {
+        config = config.with_time_steps(steps);
+    }
+    for assignment in &args.sets {
+        let (path, value) = parse_assignment(assignment).map_err(CliError::config)?;
+        config
+            .set_path(&path, value)
+            .map_err(|e| CliError::config(format!("--set {}: {}", assignment, e)))?;
+    }
+    config.validate().map_err(CliError::config)?;
+
+    match (&args.config, &args.scenario) {
+        (Some(path), _) => ui.say(format!("Configuration loaded from {}:", path.display())),
+        (None, Some(name)) => ui.say(format!("Configuration loaded from scenario '{}':", name)),
+        (None, None) => ui.say("Configuration loaded:"),
+    }
+    ui.say(format!("  Time steps: {}", config.time_steps));
+    ui.say(format!("  Initial agents: {}", config.initial_agents));
+    for overlay in &args.overlays {
+        ui.say(format!("  Overlay: {}", overlay.display()));
+    }
+    for assignment in &args.sets {
+        ui.say(format!("  Set: {}", assignment));
+    }
+    ui.say(format!("  Capacity tiers: {}", config.capacity_tiers.len()));
+    match config.random_seed {
+        Some(seed) => ui.say(format!("  Random seed: {}\n", seed)),
+        None => ui.say("  Random seed: (entropy)\n"),
+    }
+
+    Ok(config)
+}
+
+/// Records the files written into an output directory.
+struct OutputDir {
+    dir: PathBuf,
+    files: Vec<String>,
+}
+
+impl OutputDir {
+    fn create(args: &OutputArgs) -> CliResult<Self> {
+        fs::create_dir_all(&args.out_dir).map_err(|e| CliError {
+            code: EXIT_FAILURE,
+            message: format!("failed to create {}: {}", args.out_dir.display(), e),
+        })?;
+        Ok(Self {
+            dir: args.out_dir.clone(),
+            files: Vec::new(),
+        })
+    }
+
+    /// Path for `name` inside the directory, recorded in the summary.
+    fn file(&mut self, name: &str) -> String {
+        self.files.push(name.to_string());
+        self.dir.join(name).to_string_lossy().into_owned()
+    }
+
+    fn finish(mut self, command: &str, details: Value) -> CliResult<CommandSummary> {
+        let path = self.file(SUMMARY_FILE);
+        let summary = CommandSummary::new(command, Some(&self.dir), self.files, details);
+        export_to_json(&summary, &path)?;
+        Ok(summary)
+    }
+}
+
+fn run(
+    args: &ConfigArgs,
+    output: &OutputArgs,
+    report_csv: Option<&Path>,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    ui.say("Autonomous Infrastructure Risk Simulation");
+    ui.say("================================================\n");
+    let config = resolve_config(args, ui)?;
+    let mut out = OutputDir::create(output)?;
+
+    ui.say("Initializing simulation engine...");
+    let mut engine = SimulationEngine::new(config.clone()).map_err(CliError::config)?;
+
+    ui.say("Running simulation...\n");
+    let results = engine.run();
+    let summary = RunSummary::from_results(&results);
+
+    ui.say("Simulation complete!");
+    ui.say(format!(
+        "  Total reports generated: {}",
+        results.reports.len()
+    ));
+    ui.say(format!(
+        "  Final agent count: {}",
+        results.final_agent_count
+    ));
+    ui.say(format!(
+        "  Cascading failures: {}",
+        results.cascading_failures
+    ));
+    ui.say(format!(
+        "  Spillovers into linked tiers: {}",
+        summary.spillovers
+    ));
+    if results.operators.len() > 1 {
+        for operator in &results.operators {
+            ui.say(format!(
+                "  {}: {} objects in orbit, {} launches, {} collisions",
+                operator.name,
+                operator.fleet.total(),
+                operator.launches,
+                operator.collisions
+            ));
+        }
+    }
+
+    ui.say(format!("\nExporting to {}...", out.dir.display()));
+    export_to_json(&config, &out.file("config.json"))?;
+    export_to_csv(&results, &out.file("reports.csv"))?;
+    export_to_jsonl(&results.reports, &out.file("reports.jsonl"))?;
+    export_to_jsonl(&results.events, &out.file("events.jsonl"))?;
+    export_operators_to_csv(&results.operators, &out.file("operators.csv"))?;
+    export_capacity_to_csv(&results.capacity, &out.file("tier_capacity.csv"))?;
+    if let Some(path) = report_csv {
+        export_to_csv(&results, &path.to_string_lossy())?;
+    }
+    ui.say("Export complete!");
+
+    out.finish(
+        "run",
+        json!({
+            "seed": config.random_seed,
+            "time_steps": config.time_steps,
+            "reports": results.reports.len(),
+            "events": results.events.len(),
+            "report_csv": report_csv,
+            "summary": summary,
+        }),
+    )
+}
+
+fn sweep(
+    spec_path: &Path,
+    args: &ConfigArgs,
+    output: &OutputArgs,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    let config = resolve_config(args, ui)?;
+    let spec = SweepSpec::from_file(spec_path).map_err(CliError::config)?;
+    let runs = spec.expand(&config).map_err(CliError::config)?;
+    for run in &runs {
+        run.config
+            .validate()
+            .map_err(|e| CliError::config(format!("sweep run {}: {}", run.index, e)))?;
+    }
+    let mut out = OutputDir::create(output)?;
+
+    ui.say(format!(
+        "Running sweep from {} ({} runs)...",
+        spec_path.display(),
+        runs.len()
+    ));
+    let rows = spec.run(&config)?;
+
+    ui.say(format!(
+        "Exporting sweep results to {}...",
+        out.dir.display()
+    ));
+    export_to_json(&config, &out.file("config.json"))?;
+    export_sweep_to_csv(&rows, &spec.fields(), &out.file("sweep.csv"))?;
+    ui.say("Sweep complete!");
+
+    out.finish(
+        "sweep",
+        json!({
+            "spec": spec_path.to_string_lossy(),
+            "fields": spec.fields(),
+            "runs": rows.len(),
+        }),
+    )
+}
+
+fn ensemble(
+    args: &ConfigArgs,
+    runs: usize,
+    first_seed: Option<u64>,
+    output: &OutputArgs,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    let config = resolve_config(args, ui)?;
+    let first = first_seed.or(config.random_seed).unwrap_or(0);
+    let seeds: Vec<u64> = (0..runs as u64)
+        .map(|i| first.checked_add(i))
+        .collect::<Option<_>>()
+        .ok_or_else(|| {
+            CliError::config(format!(
+                "{} runs starting at seed {} would overflow the seed range",
+                runs, first
+            ))
+        })?;
+    let mut out = OutputDir::create(output)?;
+
+    ui.say(format!(
+        "Running ensemble of {} seeds starting at {}...",
+        runs, first
+    ));
+    let rows = run_ensemble(&config, &seeds).map_err(CliError::config)?;
+    let summaries: Vec<RunSummary> = rows.iter().map(|r| r.summary.clone()).collect();
+    let stats = metric_stats(&summaries);
+
+    for s in &stats {
+        ui.say(format!(
+            "  {:<24} mean {:>12.6}  p05 {:>12.6}  p95 {:>12.6}",
+            s.metric, s.mean, s.p05, s.p95
+        ));
+    }
+
+    ui.say(format!(
+        "Exporting ensemble results to {}...",
+        out.dir.display()
+    ));
+    export_to_json(&config, &out.file("config.json"))?;
+    export_sweep_to_csv(&rows, &[], &out.file("ensemble.csv"))?;
+    ui.say("Ensemble complete!");
+
+    out.finish(
+        "ensemble",
+        json!({
+            "runs": rows.len(),
+            "seeds": seeds,
+            "stats": stats,
+        }),
+    )
+}
+
+fn sensitivity(
+    spec_path: &Path,
+    args: &ConfigArgs,
+    output: &OutputArgs,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    let config = resolve_config(args, ui)?;
+    let spec = SensitivitySpec::from_file(spec_path).map_err(CliError::config)?;
+    let mut out = OutputDir::create(output)?;
+
+    ui.say(format!(
+        "Running sensitivity analysis from {} ({} runs)...",
+        spec_path.display(),
+        spec.run_count()
+    ));
+    let report = spec.run(&config)?;
+
+    ui.say(format!(
+        "Exporting sensitivity indices to {}...",
+        out.dir.display()
+    ));
+    export_to_json(&config, &out.file("config.json"))?;
+    export_sensitivity_to_csv(&report, &out.file("sensitivity.csv"))?;
+    ui.say("Sensitivity analysis complete!");
+
+    let method = match report {
+        SensitivityReport::Sobol(_) => "sobol",
+        SensitivityReport::Morris(_) => "morris",
+    };
+    out.finish(
+        "sensitivity",
+        json!({
+            "spec": spec_path.to_string_lossy(),
+            "method": method,
+            "runs": spec.run_count(),
+            "metrics": spec.metrics,
+        }),
+    )
+}
+
+fn replay(
+    dir: &Path,
+    hedging: Option<bool>,
+    confidence_modulation: Option<bool>,
+    output: Option<PathBuf>,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    let config: SimulationConfig = import_json(&path("config.json"))?;
+    let reports: Vec<StatusReport> = import_jsonl(&path("reports.jsonl"))?;
+    let events: Vec<Event> = import_jsonl(&path("events.jsonl"))?;
+
+    let mut reporting: ReportingConfig = config.reporting;
+    if let Some(hedging) = hedging {
+        reporting.hedging_enabled = hedging;
+    }
+    if let Some(modulation) = confidence_modulation {
+        reporting.confidence_modulation = modulation;
+    }
+
+    ui.say(format!(
+        "Replaying {} reports from {} events in {}...",
+        reports.len(),
+        events.len(),
+        dir.display()
+    ));
+    let replayed = replay_reports(&reports, &events, &reporting)?;
+    let changed = reports
+        .iter()
+        .zip(&replayed)
+        .filter(|(a, b)| a.report_text != b.report_text)
+        .count();
+
+    let output = output.unwrap_or_else(|| dir.join("reports_replayed.csv"));
+    let output_path = output.to_string_lossy().into_owned();
+    export_reports_to_csv(&replayed, &output_path)?;
+    ui.say(format!(
+        "Wrote {} ({} reports changed)",
+        output_path, changed
+    ));
+
+    Ok(CommandSummary::new(
+        "replay",
+        None,
+        vec![output_path],
+        json!({
+            "source": dir.to_string_lossy(),
+            "reports": replayed.len(),
+            "changed": changed,
+            "reporting": reporting,
+        }),
+    ))
+}
+
+fn graph(
+    dir: &Path,
+    format: GraphFormat,
+    output: Option<PathBuf>,
+    ui: &Ui,
+) -> CliResult<CommandSummary> {
+    let events: Vec<Event> = import_jsonl(&dir.join("events.jsonl").to_string_lossy())?;
+    let graph = EventGraph::new(&events)?;
+
+    let default_name = match format {
+        GraphFormat::Dot => "event_graph.dot",
+        GraphFormat::Graphml => "event_graph.graphml",
+    };
+    let output_path = output
+        .unwrap_or_else(|| dir.join(default_name))
+        .to_string_lossy()
+        .into_owned();
+    match format {
+        GraphFormat::Dot => export_event_graph_to_dot(&graph, &output_path)?,
+        GraphFormat::Graphml => export_event_graph_to_graphml(&graph, &output_path)?,
+    }
+
+    let roots = graph.damage_by_root();
+    let linked = (0..graph.len()).filter(|&id| graph.is_linked(id)).count();
+    ui.say(format!(
+        "Wrote {} ({} of {} events in causal chains)",
+        output_path,
+        linked,
+        graph.len()
+    ));
+    ui.say(format!("  Root causes: {}", roots.len()));
+    ui.say(format!("  Longest chain: {} links", graph.max_depth()));
+    for &(root, damage) in roots.iter().take(5) {
+        let event = graph.event(root);
+        ui.say(format!(
+            "  Event {} ({} at t={}): {} descendants, {:.2} damage",
+            root,
+            event.event_type.name(),
+            event.timestamp,
+            graph.descendants(root).len() - 1,
+            damage
+        ));
+    }
+
+    let top_roots: Vec<Value> = roots
+        .iter()
+        .take(10)
+        .map(|&(root, damage)| json!({ "event_id": root, "damage": damage }))
+        .collect();
+    Ok(CommandSummary::new(
+        "graph",
+        None,
+        vec![output_path],
+        json!({
+            "source": dir.to_string_lossy(),
+            "events": graph.len(),
+            "linked_events": linked,
+            "roots": roots.len(),
+            "max_depth": graph.max_depth(),
+            "top_roots": top_roots,
+        }),
+    ))
+}
+
+fn inspect(dir: &Path, ui: &Ui) -> CliResult<CommandSummary> {
+    let entries = fs::read_dir(dir).map_err(|e| CliError {
+        code: EXIT_FAILURE,
+        message: format!("failed to read {}: {}", dir.display(), e),
+    })?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let bytes = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        let rows = match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => count_lines(&path).map(|n| n.saturating_sub(1)),
+            Some("jsonl") => count_lines(&path),
+            _ => None,
+        };
+        files.push(json!({ "name": name, "bytes": bytes, "rows": rows }));
+    }
+    files.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let summary_path = dir.join(SUMMARY_FILE);
+    let previous: Option<CommandSummary> = if summary_path.exists() {
+        Some(import_json(&summary_path.to_string_lossy())?)
+    } else {
+        None
+    };
+
+    ui.say(format!("Output directory: {}", dir.display()));
+    match &previous {
+        Some(s) => ui.say(format!("  Produced by `{}` at {}", s.command, s.created_at)),
+        None => ui.say(format!("  No {} found", SUMMARY_FILE)),
+    }
+    for file in &files {
+        let rows = match file["rows"].as_u64() {
+            Some(n) => format!("{} rows", n),
+            None => String::new(),
+        };
+        ui.say(format!(
+            "  {:<24} {:>10} bytes  {}",
+            file["name"].as_str().unwrap_or_default(),
+            file["bytes"],
+            rows
+        ));
+    }
+    if let Some(s) = &previous {
+        if !s.details.is_null() {
+            ui.say(format!(
+                "  Details: {}",
+                serde_json::to_string_pretty(&s.details).unwrap_or_default()
+            ));
+        }
+    }
+
+    Ok(CommandSummary::new(
+        "inspect",
+        Some(dir),
+        Vec::new(),
+        json!({ "files": files, "summary": previous }),
+    ))
+}
+
+fn count_lines(path: &Path) -> Option<u64> {
+    let contents = fs::read_to_string(path).ok()?;
+    Some(contents.lines().filter(|l| !l.trim().is_empty()).count() as u64)
+}
+
+fn scenarios(ui: &Ui) -> CliResult<CommandSummary> {
+    for scenario in SCENARIOS {
+        ui.say(format!("{:<28} {}", scenario.name, scenario.description));
+    }
+    let list: Vec<Value> = SCENARIOS
+        .iter()
+        .map(|s| json!({ "name": s.name, "description": s.description }))
+        .collect();
+
+    Ok(CommandSummary::new(
+        "scenarios",
+        None,
+        Vec::new(),
+        json!({ "scenarios": list }),
+    ))
+}
+
+fn schema(output: Option<PathBuf>, ui: &Ui) -> CliResult<CommandSummary> {
+    let schema = simulation_config_schema();
+    let files = match output {
+        Some(path) => {
+            let path = path.to_string_lossy().into_owned();
+            export_to_json(&schema, &path)?;
+            ui.say(format!("Wrote JSON Schema to {}", path));
+            vec![path]
+        }
+        None => {
+            ui.say(serde_json::to_string_pretty(&schema).unwrap_or_default());
+            Vec::new()
+        }
+    };
+
+    Ok(CommandSummary::new(
+        "schema",
+        None,
+        files,
+        json!({ "schema": schema }),
+    ))
+}
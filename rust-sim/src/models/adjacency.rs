@@ -0,0 +1,94 @@
+use crate::config::parameters::SpilloverConfig;
+use crate::models::capacity_tier::{next_tier_up, CapacityTier};
+use std::collections::BTreeMap;
+
+/// Which tiers a cascade can spill into from each tier, and how likely it is.
+#[derive(Debug, Clone, Default)]
+pub struct TierAdjacency {
+    /// Per tier, the (tier id, spillover probability) of each neighbour.
+    neighbors: Vec<Vec<(usize, f64)>>,
+}
+
+impl TierAdjacency {
+    /// Link each tier to the tiers directly above and below it, then apply
+    /// the explicit links. Links naming a tier that does not exist yet, such
+    /// as one an intervention adds later, are skipped.
+    pub fn build(tiers: &[CapacityTier], config: &SpilloverConfig) -> Self {
+        let mut probabilities = vec![BTreeMap::new(); tiers.len()];
+        for id in 0..tiers.len() {
+            if let Some(up) = next_tier_up(tiers, id) {
+                probabilities[id].insert(up, config.neighbor_probability);
+                probabilities[up].insert(id, config.neighbor_probability);
+            }
+        }
+
+        let position = |name: &str| tiers.iter().position(|t| t.name == name);
+        for link in &config.links {
+            let (Some(from), Some(to)) = (position(&link.from), position(&link.to)) else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            probabilities[from].insert(to, link.probability);
+            if !link.one_way {
+                probabilities[to].insert(from, link.probability);
+            }
+        }
+
+        Self {
+            neighbors: probabilities
+                .into_iter()
+                .map(|p| {
+                    p.into_iter()
+                        .filter(|&(_, probability)| probability > 0.0)
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Tiers a cascade in `tier_id` can spill into, by id.
+    pub fn neighbors(&self, tier_id: usize) -> &[(usize, f64)] {
+        self.neighbors.get(tier_id).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parameters::TierLinkConfig;
+
+    #[test]
+    fn test_shells_and_links() {
+        let tiers = vec![
+            CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(300.0, 500.0),
+            CapacityTier::new(1, "High".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(800.0, 1000.0),
+            CapacityTier::new(2, "Mid".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(500.0, 800.0),
+        ];
+        let link = |from: &str, to: &str, probability, one_way| TierLinkConfig {
+            from: from.to_string(),
+            to: to.to_string(),
+            probability,
+            one_way,
+        };
+        let config = SpilloverConfig {
+            neighbor_probability: 0.2,
+            damage_scale: 0.5,
+            links: vec![
+                link("Low", "High", 0.05, true),
+                link("Mid", "High", 0.0, false),
+                link("Low", "Polar", 1.0, false),
+            ],
+        };
+
+        let adjacency = TierAdjacency::build(&tiers, &config);
+        assert_eq!(adjacency.neighbors(0), &[(1, 0.05), (2, 0.2)]);
+        assert_eq!(adjacency.neighbors(1), &[]);
+        assert_eq!(adjacency.neighbors(2), &[(0, 0.2)]);
+        assert_eq!(adjacency.neighbors(3), &[]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::config::parameters::AgentPropertiesConfig;
+use crate::models::capacity_tier::CapacityTier;
+use crate::models::lifecycle::LifecycleState;
+use crate::models::object_kind::ObjectKind;
+use crate::models::subsystem::{Subsystem, SubsystemHealth};
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+
+/// Cross-sectional area at which an agent sees exactly its tier's failure rate.
+pub const REFERENCE_AREA_M2: f64 = 10.0;
+
+/// Health below which an active satellite loses control and becomes defunct.
+pub const DEFUNCT_HEALTH: f64 = 0.25;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agent {
+    pub id: usize,
+    pub tier_id: usize,
+    #[serde(default)]
+    pub kind: ObjectKind,
+    /// Owner of the object; debris is attributed to the owner of its parent.
+    #[serde(default)]
+    pub operator_id: usize,
+    #[serde(default)]
+    pub lifecycle: LifecycleState,
+    /// Timestep at which the object entered its current lifecycle state.
+    #[serde(default)]
+    pub lifecycle_since: usize,
+    /// Timesteps since launch.
+    pub age: usize,
+    pub compliance: bool,
+    /// Structural integrity; the object is destroyed when it reaches zero.
+    pub health: f64,
+    #[serde(default)]
+    pub subsystems: SubsystemHealth,
+    pub altitude_km: f64,
+    pub inclination_deg: f64,
+    pub mass_kg: f64,
+    pub area_m2: f64,
+}
+
+impl Agent {
+    pub fn new(id: usize, tier_id: usize, compliance: bool) -> Self {
+        Self {
+            id,
+            tier_id,
+            kind: ObjectKind::ActiveSatellite,
+            operator_id: 0,
+            lifecycle: LifecycleState::Operational,
+            lifecycle_since: 0,
+            age: 0,
+            compliance,
+            health: 1.0,
+            subsystems: SubsystemHealth::default(),
+            altitude_km: 0.0,
+            inclination_deg: 0.0,
+            mass_kg: 1.0,
+            area_m2: REFERENCE_AREA_M2,
+        }
+    }
+
+    /// A new object of `kind` at a random altitude in `tier`, with physical
+    /// properties drawn from `properties`.
+    pub fn spawn(
+        id: usize,
+        tier: &CapacityTier,
+        kind: ObjectKind,
+        compliance: bool,
+        properties: &AgentPropertiesConfig,
+        rng: &mut SimulationRng,
+    ) -> Self {
+        let altitude = tier.sample_altitude(rng);
+        let inclination = properties.inclination_deg.sample(rng);
+        let mass = properties.mass_kg.sample(rng);
+        let area = properties.area_m2.sample(rng);
+        Self::new(id, tier.id, compliance)
+            .with_kind(kind)
+            .with_orbit(altitude, inclination, mass, area)
+    }
+
+    /// Objects outside mitigation policy are never counted as compliant,
+    /// and anything but an active satellite starts out derelict.
+    pub fn with_kind(mut self, kind: ObjectKind) -> Self {
+        self.kind = kind;
+        self.compliance &= kind.subject_to_compliance();
+        if kind != ObjectKind::ActiveSatellite {
+            self.lifecycle = LifecycleState::Derelict;
+        }
+        self
+    }
+
+    pub fn with_lifecycle(mut self, state: LifecycleState, since: usize) -> Self {
+        self.lifecycle = state;
+        self.lifecycle_since = since;
+        self
+    }
+
+    pub fn with_operator(mut self, operator_id: usize) -> Self {
+        self.operator_id = operator_id;
+        self
+    }
+
+    /// A live object of a maneuverable kind with working power and propulsion.
+    pub fn can_maneuver(&self) -> bool {
+        self.kind.can_maneuver()
+            && self.is_alive()
+            && self.subsystems.is_functional(Subsystem::Power)
+            && self.subsystems.is_functional(Subsystem::Propulsion)
+    }
+
+    /// An active satellite with working power and comms.
+    pub fn can_communicate(&self) -> bool {
+        self.kind == ObjectKind::ActiveSatellite
+            && self.is_alive()
+            && self.subsystems.is_functional(Subsystem::Power)
+            && self.subsystems.is_functional(Subsystem::Comms)
+    }
+
+    /// Whether the operator can order a maneuver, e.g. a deorbit burn.
+    pub fn accepts_commands(&self) -> bool {
+        self.lifecycle.accepts_commands() && self.can_communicate() && self.can_maneuver()
+    }
+
+    /// An active satellite that has lost power or too much structural
+    /// health, and so should become derelict.
+    pub fn has_failed(&self) -> bool {
+        let failed =
+            self.health < DEFUNCT_HEALTH || !self.subsystems.is_functional(Subsystem::Power);
+        self.kind == ObjectKind::ActiveSatellite && self.is_alive() && failed
+    }
+
+    pub fn with_orbit(
+        mut self,
+        altitude_km: f64,
+        inclination_deg: f64,
+        mass_kg: f64,
+        area_m2: f64,
+    ) -> Self {
+        self.altitude_km = altitude_km;
+        self.inclination_deg = inclination_deg;
+        self.mass_kg = mass_kg;
+        self.area_m2 = area_m2;
+        self
+    }
+
+    /// Area-to-mass ratio in m²/kg; higher values decay faster under drag.
+    pub fn area_to_mass(&self) -> f64 {
+        self.area_m2 / self.mass_kg
+    }
+
+    /// Collision cross-section relative to the reference object.
+    pub fn cross_section_factor(&self) -> f64 {
+        self.area_m2 / REFERENCE_AREA_M2
+    }
+
+    pub fn tick(&mut self) {
+        self.age += 1;
+        // Health degrades slightly over time
+        self.health *= 0.9999;
+    }
+
+    /// Still in orbit and not destroyed.
+    pub fn is_alive(&self) -> bool {
+        self.health > 0.01 && self.lifecycle != LifecycleState::Removed
+    }
+
+    pub fn apply_damage(&mut self, damage: f64) {
+        self.health -= damage;
+        if self.health < 0.0 {
+            self.health = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_creation() {
+        let agent = Agent::new(1, 0, true);
+        assert_eq!(agent.id, 1);
+        assert_eq!(agent.tier_id, 0);
+        assert!(agent.is_alive());
+    }
+
+    #[test]
+    fn test_agent_damage() {
+        let mut agent = Agent::new(1, 0, true);
+        agent.apply_damage(0.5);
+        assert_eq!(agent.health, 0.5);
+        assert!(agent.is_alive());
+
+        agent.apply_damage(0.6);
+        assert!(!agent.is_alive());
+    }
+
+    #[test]
+    fn test_damaged_satellite_has_failed() {
+        let mut agent = Agent::new(1, 0, true);
+        assert!(!agent.has_failed());
+        agent.apply_damage(0.8);
+        assert!(agent.has_failed());
+
+        let debris = Agent::new(2, 0, true).with_kind(ObjectKind::DebrisFragment);
+        assert!(!debris.compliance && !debris.has_failed());
+        assert_eq!(debris.lifecycle, LifecycleState::Derelict);
+    }
+
+    #[test]
+    fn test_subsystems_gate_capabilities() {
+        let mut agent = Agent::new(1, 0, true);
+        assert!(agent.accepts_commands());
+
+        agent.subsystems.fail(Subsystem::Comms);
+        assert!(agent.can_maneuver() && !agent.can_communicate() && !agent.accepts_commands());
+
+        agent.subsystems = SubsystemHealth::default();
+        agent.subsystems.fail(Subsystem::Propulsion);
+        assert!(!agent.can_maneuver() && agent.can_communicate() && !agent.has_failed());
+
+        agent.subsystems.fail(Subsystem::Power);
+        assert!(!agent.can_communicate());
+        assert!(agent.has_failed());
+    }
+}
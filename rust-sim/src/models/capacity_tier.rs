@@ -1,94 +1,287 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CapacityTier {
-    pub id: usize,
-    pub name: String,
-    pub max_capacity: usize,
-    pub current_count: usize,
-    pub congestion_threshold: f64,
-    pub failure_base_rate: f64,
-}
-
-impl CapacityTier {
-    pub fn new(
-        id: usize,
-        name: String,
-        max_capacity: usize,
-        congestion_threshold: f64,
-        failure_base_rate: f64,
-    ) -> Self {
-        Self {
-            id,
-            name,
-            max_capacity,
-            current_count: 0,
-            congestion_threshold,
-            failure_base_rate,
-        }
-    }
-
-    pub fn utilization(&self) -> f64 {
-        self.current_count as f64 / self.max_capacity as f64
-    }
-
-    pub fn is_congested(&self) -> bool {
-        self.utilization() > self.congestion_threshold
-    }
-
-    pub fn congestion_severity(&self) -> f64 {
-        if self.utilization() <= self.congestion_threshold {
-            0.0
-        } else {
-            (self.utilization() - self.congestion_threshold)
-                / (1.0 - self.congestion_threshold)
-        }
-    }
-
-    pub fn effective_failure_rate(&self) -> f64 {
-        let base = self.failure_base_rate;
-        let utilization = self.utilization();
-        
-        // Exponential increase in failure rate as capacity is approached
-        if utilization < self.congestion_threshold {
-            base
-        } else {
-            let excess = utilization - self.congestion_threshold;
-            base * (1.0 + 10.0 * excess.powi(2))
-        }
-    }
-
-    pub fn add_agent(&mut self) {
-        self.current_count += 1;
-    }
-
-    pub fn remove_agent(&mut self) {
-        if self.current_count > 0 {
-            self.current_count -= 1;
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_utilization() {
-        let mut tier = CapacityTier::new(0, "Test".to_string(), 100, 0.7, 0.001);
-        assert_eq!(tier.utilization(), 0.0);
-        
-        tier.current_count = 50;
-        assert_eq!(tier.utilization(), 0.5);
-    }
-
-    #[test]
-    fn test_congestion() {
-        let mut tier = CapacityTier::new(0, "Test".to_string(), 100, 0.7, 0.001);
-        tier.current_count = 60;
-        assert!(!tier.is_congested());
-        
-        tier.current_count = 80;
-        assert!(tier.is_congested());
-    }
-}
\ No newline at end of file
+use crate::config::parameters::{DebrisDegradation, TierConfig, ValueRange};
+use crate::config::schedule::Schedule;
+use crate::models::failure_rate::{FailureCurve, FailureRateModel};
+use crate::models::object_kind::{KindCounts, ObjectKind};
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityTier {
+    pub id: usize,
+    pub name: String,
+    pub min_altitude_km: f64,
+    pub max_altitude_km: f64,
+    pub max_capacity: usize,
+    pub current_count: usize,
+    #[serde(default)]
+    pub kind_counts: KindCounts,
+    pub congestion_threshold: f64,
+    pub failure_base_rate: f64,
+    #[serde(default)]
+    pub failure_curve: FailureCurve,
+    #[serde(default)]
+    pub debris_degradation: DebrisDegradation,
+    congestion_threshold_schedule: Schedule,
+    failure_base_rate_schedule: Schedule,
+}
+
+impl CapacityTier {
+    pub fn new(
+        id: usize,
+        name: String,
+        max_capacity: usize,
+        congestion_threshold: f64,
+        failure_base_rate: f64,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            min_altitude_km: 0.0,
+            max_altitude_km: 0.0,
+            max_capacity,
+            current_count: 0,
+            kind_counts: KindCounts::default(),
+            congestion_threshold,
+            failure_base_rate,
+            failure_curve: FailureCurve::default(),
+            debris_degradation: DebrisDegradation::default(),
+            congestion_threshold_schedule: Schedule::Constant(congestion_threshold),
+            failure_base_rate_schedule: Schedule::Constant(failure_base_rate),
+        }
+    }
+
+    pub fn from_config(id: usize, config: &TierConfig) -> Self {
+        let mut tier = Self::new(
+            id,
+            config.name.clone(),
+            config.max_capacity,
+            config.congestion_threshold.value_at(0),
+            config.failure_base_rate.value_at(0),
+        );
+        tier.apply_config(config);
+        tier
+    }
+
+    pub fn with_altitude_band(mut self, min_altitude_km: f64, max_altitude_km: f64) -> Self {
+        self.min_altitude_km = min_altitude_km;
+        self.max_altitude_km = max_altitude_km;
+        self
+    }
+
+    /// Adopt a (possibly changed) tier configuration, keeping the current occupancy.
+    pub fn apply_config(&mut self, config: &TierConfig) {
+        self.name = config.name.clone();
+        (self.min_altitude_km, self.max_altitude_km) = config.band(self.id);
+        self.max_capacity = config.max_capacity;
+        self.congestion_threshold_schedule = config.congestion_threshold.clone();
+        self.failure_base_rate_schedule = config.failure_base_rate.clone();
+        self.failure_curve = config.failure_curve.clone();
+        self.debris_degradation = config.debris_degradation.clone();
+    }
+
+    /// Refresh scheduled parameters to their values at timestep `t`.
+    pub fn update_schedules(&mut self, t: usize) {
+        self.congestion_threshold = self.congestion_threshold_schedule.value_at(t);
+        self.failure_base_rate = self.failure_base_rate_schedule.value_at(t);
+    }
+
+    /// Uniform random altitude within the band.
+    pub fn sample_altitude(&self, rng: &mut SimulationRng) -> f64 {
+        ValueRange::new(self.min_altitude_km, self.max_altitude_km).sample(rng)
+    }
+
+    pub fn contains_altitude(&self, altitude_km: f64) -> bool {
+        altitude_km >= self.min_altitude_km && altitude_km < self.max_altitude_km
+    }
+
+    /// Distance in km from `altitude_km` to the band; zero inside it.
+    pub fn altitude_distance(&self, altitude_km: f64) -> f64 {
+        if altitude_km < self.min_altitude_km {
+            self.min_altitude_km - altitude_km
+        } else if altitude_km > self.max_altitude_km {
+            altitude_km - self.max_altitude_km
+        } else {
+            0.0
+        }
+    }
+
+    /// Volume of the spherical shell spanned by the altitude band, in km³.
+    pub fn shell_volume_km3(&self) -> f64 {
+        let inner = EARTH_RADIUS_KM + self.min_altitude_km;
+        let outer = EARTH_RADIUS_KM + self.max_altitude_km;
+        4.0 / 3.0 * PI * (outer.powi(3) - inner.powi(3))
+    }
+
+    /// Objects per km³ in the shell.
+    pub fn spatial_density(&self) -> f64 {
+        let volume = self.shell_volume_km3();
+        if volume > 0.0 {
+            self.current_count as f64 / volume
+        } else {
+            0.0
+        }
+    }
+
+    /// Capacity left once the debris in the tier is accounted for; never
+    /// below the configured share of `max_capacity`.
+    pub fn effective_capacity(&self) -> f64 {
+        let nominal = self.max_capacity as f64;
+        let degradation = &self.debris_degradation;
+        let lost = degradation.capacity_per_fragment * self.kind_counts.debris_fragment as f64;
+        (nominal - lost).max(nominal * degradation.min_capacity_fraction)
+    }
+
+    /// Occupancy as a share of the effective capacity.
+    pub fn utilization(&self) -> f64 {
+        self.current_count as f64 / self.effective_capacity()
+    }
+
+    /// Share of effective capacity taken up by objects of one kind.
+    pub fn utilization_by_kind(&self, kind: ObjectKind) -> f64 {
+        self.kind_counts.get(kind) as f64 / self.effective_capacity()
+    }
+
+    pub fn is_congested(&self) -> bool {
+        self.utilization() > self.congestion_threshold
+    }
+
+    pub fn congestion_severity(&self) -> f64 {
+        if self.utilization() <= self.congestion_threshold {
+            0.0
+        } else {
+            (self.utilization() - self.congestion_threshold) / (1.0 - self.congestion_threshold)
+        }
+    }
+
+    /// Collision probability at the current utilization, following the
+    /// tier's failure curve.
+    pub fn effective_failure_rate(&self) -> f64 {
+        self.failure_curve.rate(
+            self.failure_base_rate,
+            self.utilization(),
+            self.congestion_threshold,
+        )
+    }
+
+    pub fn add_agent(&mut self, kind: ObjectKind) {
+        self.current_count += 1;
+        self.kind_counts.increment(kind);
+    }
+
+    pub fn remove_agent(&mut self, kind: ObjectKind) {
+        if self.current_count > 0 {
+            self.current_count -= 1;
+        }
+        self.kind_counts.decrement(kind);
+    }
+
+    /// Move one object of `kind` from tier `from` to tier `to`, updating both
+    /// counts together so total occupancy is preserved.
+    pub fn transfer(tiers: &mut [CapacityTier], from: usize, to: usize, kind: ObjectKind) {
+        if from == to {
+            return;
+        }
+        debug_assert!(
+            tiers[from].kind_counts.get(kind) > 0,
+            "transfer from an empty tier"
+        );
+        tiers[from].remove_agent(kind);
+        tiers[to].add_agent(kind);
+    }
+
+    /// Move one object between kind counts, e.g. when a satellite fails.
+    pub fn reclassify(&mut self, from: ObjectKind, to: ObjectKind) {
+        self.kind_counts.decrement(from);
+        self.kind_counts.increment(to);
+    }
+}
+
+/// Index of the tier directly above tier `id`: the lowest band starting at
+/// or above its upper edge.
+pub fn next_tier_up(tiers: &[CapacityTier], id: usize) -> Option<usize> {
+    let ceiling = tiers[id].max_altitude_km;
+    tiers
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.min_altitude_km >= ceiling)
+        .min_by(|(_, a), (_, b)| a.min_altitude_km.total_cmp(&b.min_altitude_km))
+        .map(|(i, _)| i)
+}
+
+/// Index of the tier whose altitude band contains `altitude_km`, or of the
+/// nearest band when the altitude falls in a gap or outside every band.
+pub fn tier_for_altitude(tiers: &[CapacityTier], altitude_km: f64) -> usize {
+    if let Some(i) = tiers.iter().position(|t| t.contains_altitude(altitude_km)) {
+        return i;
+    }
+    tiers
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            a.altitude_distance(altitude_km)
+                .total_cmp(&b.altitude_distance(altitude_km))
+        })
+        .map_or(0, |(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utilization() {
+        let mut tier = CapacityTier::new(0, "Test".to_string(), 100, 0.7, 0.001);
+        assert_eq!(tier.utilization(), 0.0);
+
+        tier.current_count = 50;
+        assert_eq!(tier.utilization(), 0.5);
+    }
+
+    #[test]
+    fn test_congestion() {
+        let mut tier = CapacityTier::new(0, "Test".to_string(), 100, 0.7, 0.001);
+        tier.current_count = 60;
+        assert!(!tier.is_congested());
+
+        tier.current_count = 80;
+        assert!(tier.is_congested());
+    }
+
+    #[test]
+    fn test_debris_shrinks_effective_capacity() {
+        let mut tier = CapacityTier::new(0, "Test".to_string(), 100, 0.7, 0.001);
+        for _ in 0..40 {
+            tier.add_agent(ObjectKind::DebrisFragment);
+        }
+        assert_eq!(tier.effective_capacity(), 80.0);
+        assert_eq!(tier.utilization(), 0.5);
+
+        for _ in 0..200 {
+            tier.add_agent(ObjectKind::DebrisFragment);
+        }
+        assert_eq!(tier.effective_capacity(), 25.0);
+
+        for _ in 0..200 {
+            tier.remove_agent(ObjectKind::DebrisFragment);
+        }
+        assert_eq!(tier.effective_capacity(), 80.0);
+    }
+
+    #[test]
+    fn test_tier_for_altitude() {
+        let tiers = vec![
+            CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(300.0, 500.0),
+            CapacityTier::new(1, "High".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(600.0, 800.0),
+        ];
+        assert_eq!(tier_for_altitude(&tiers, 400.0), 0);
+        assert_eq!(tier_for_altitude(&tiers, 500.0), 0);
+        assert_eq!(tier_for_altitude(&tiers, 580.0), 1);
+        assert_eq!(tier_for_altitude(&tiers, 200.0), 0);
+        assert_eq!(tier_for_altitude(&tiers, 2000.0), 1);
+    }
+}
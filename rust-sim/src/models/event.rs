@@ -1,70 +1,504 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum EventType {
-    Launch,
-    Collision,
-    Deorbit,
-    CascadingFailure,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Event {
-    pub timestamp: usize,
-    pub event_type: EventType,
-    pub tier_id: usize,
-    pub agent_ids: Vec<usize>,
-    pub severity: f64,
-}
-
-impl Event {
-    pub fn launch(timestamp: usize, tier_id: usize, agent_id: usize) -> Self {
-        Self {
-            timestamp,
-            event_type: EventType::Launch,
-            tier_id,
-            agent_ids: vec![agent_id],
-            severity: 0.0,
-        }
-    }
-
-    pub fn collision(
-        timestamp: usize,
-        tier_id: usize,
-        agent_ids: Vec<usize>,
-        severity: f64,
-    ) -> Self {
-        Self {
-            timestamp,
-            event_type: EventType::Collision,
-            tier_id,
-            agent_ids,
-            severity,
-        }
-    }
-
-    pub fn deorbit(timestamp: usize, tier_id: usize, agent_id: usize) -> Self {
-        Self {
-            timestamp,
-            event_type: EventType::Deorbit,
-            tier_id,
-            agent_ids: vec![agent_id],
-            severity: 0.0,
-        }
-    }
-
-    pub fn cascading_failure(
-        timestamp: usize,
-        tier_id: usize,
-        agent_ids: Vec<usize>,
-        severity: f64,
-    ) -> Self {
-        Self {
-            timestamp,
-            event_type: EventType::CascadingFailure,
-            tier_id,
-            agent_ids,
-            severity,
-        }
-    }
-}
\ No newline at end of file
+use crate::models::lifecycle::LifecycleState;
+use crate::models::subsystem::Subsystem;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// What happened, with the details specific to that kind of event.
+/// Serialized with a `type` tag, e.g. `{"type": "collision", "damage": 0.3}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventType {
+    /// A new satellite, and any upper stage left in orbit, was launched.
+    Launch {
+        operator_id: usize,
+    },
+    /// Two objects collided, losing `damage` health between them.
+    Collision {
+        damage: f64,
+    },
+    /// Screening predicted a close approach between two objects.
+    ConjunctionWarning {
+        miss_distance_km: f64,
+    },
+    /// The operator moved the object out of a predicted close approach.
+    AvoidanceManeuver {
+        miss_distance_km: f64,
+        /// Whether the approach would otherwise have been a collision.
+        collision_avoided: bool,
+    },
+    Deorbit {
+        reason: DeorbitReason,
+    },
+    /// Uncontrolled re-entry after the orbit decayed.
+    NaturalReentry {
+        mass_kg: f64,
+    },
+    /// Several objects in an overloaded tier failed together, losing
+    /// `damage` health between them.
+    CascadingFailure {
+        congestion: f64,
+        damage: f64,
+    },
+    /// A cascade in `from_tier` spread into this event's tier, costing the
+    /// objects hit `damage` health between them.
+    Spillover {
+        from_tier: usize,
+        damage: f64,
+    },
+    /// A breakup of the objects in `agent_ids` into new debris.
+    Fragmentation {
+        fragments: usize,
+        mass_kg: f64,
+    },
+    /// A subsystem of the agent stopped working.
+    SubsystemFailure {
+        subsystem: Subsystem,
+    },
+    /// An object moved from one lifecycle state to the next.
+    LifecycleTransition {
+        from: LifecycleState,
+        to: LifecycleState,
+    },
+    /// An object moved between tiers; `tier_id` is the destination.
+    Migration {
+        from_tier: usize,
+        to_tier: usize,
+        cause: MigrationCause,
+    },
+    /// An operator broke a mitigation rule.
+    ComplianceViolation {
+        rule: ComplianceRule,
+    },
+    /// A configuration parameter changed mid-run.
+    PolicyChange {
+        field: String,
+        old_value: Value,
+        new_value: Value,
+    },
+    /// Solar activity moved into a new band.
+    SpaceWeather {
+        solar_flux: f64,
+        activity: SolarActivity,
+    },
+    /// A status report was generated.
+    ReportIssued {
+        stability_class: String,
+        utilization: f64,
+    },
+    Intervention {
+        description: String,
+    },
+}
+
+impl EventType {
+    /// The `type` tag the event is serialized with.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventType::Launch { .. } => "launch",
+            EventType::Collision { .. } => "collision",
+            EventType::ConjunctionWarning { .. } => "conjunction_warning",
+            EventType::AvoidanceManeuver { .. } => "avoidance_maneuver",
+            EventType::Deorbit { .. } => "deorbit",
+            EventType::NaturalReentry { .. } => "natural_reentry",
+            EventType::CascadingFailure { .. } => "cascading_failure",
+            EventType::Spillover { .. } => "spillover",
+            EventType::Fragmentation { .. } => "fragmentation",
+            EventType::SubsystemFailure { .. } => "subsystem_failure",
+            EventType::LifecycleTransition { .. } => "lifecycle_transition",
+            EventType::Migration { .. } => "migration",
+            EventType::ComplianceViolation { .. } => "compliance_violation",
+            EventType::PolicyChange { .. } => "policy_change",
+            EventType::SpaceWeather { .. } => "space_weather",
+            EventType::ReportIssued { .. } => "report_issued",
+            EventType::Intervention { .. } => "intervention",
+        }
+    }
+
+    /// Health lost by the objects involved.
+    pub fn damage(&self) -> f64 {
+        match self {
+            EventType::Collision { damage }
+            | EventType::CascadingFailure { damage, .. }
+            | EventType::Spillover { damage, .. } => *damage,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Why an object changed tier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationCause {
+    /// Drag lowered the orbit below the tier's band.
+    Decay,
+    /// The operator raised the orbit to escape congestion.
+    OrbitRaising,
+    /// An intervention added a tier or moved a band around the object.
+    BandChange,
+}
+
+/// Why a satellite was deorbited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeorbitReason {
+    /// Routine disposal at the end of its design life.
+    Disposal,
+    /// Removed by policy to relieve a congested tier.
+    Congestion,
+    /// Ordered by a scenario intervention.
+    Intervention,
+}
+
+/// A mitigation rule an operator can break.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceRule {
+    /// A retired satellite was abandoned instead of deorbited.
+    PostMissionDisposal,
+    /// A spent upper stage was left in orbit.
+    UpperStageDisposal,
+}
+
+/// Solar activity relative to the configured range of the cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolarActivity {
+    Low,
+    Moderate,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// Position of the event in the run's log; see [`EventLog`].
+    #[serde(default)]
+    pub id: usize,
+    pub timestamp: usize,
+    pub event_type: EventType,
+    /// Tier the event happened in; absent for run-wide events such as
+    /// reports, space weather and policy changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier_id: Option<usize>,
+    pub agent_ids: Vec<usize>,
+    /// Earlier events that led to this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parent_ids: Vec<usize>,
+}
+
+/// An append-only event log in which each event's id is its position, so
+/// that later events can name the events that caused them.
+pub trait EventLog {
+    /// Append `event`, assigning its id, and return the id.
+    fn log(&mut self, event: Event) -> usize;
+}
+
+impl EventLog for Vec<Event> {
+    fn log(&mut self, mut event: Event) -> usize {
+        let id = self.len();
+        debug_assert!(
+            event.parent_ids.iter().all(|&p| p < id),
+            "parents must be logged first"
+        );
+        event.id = id;
+        self.push(event);
+        id
+    }
+}
+
+impl Event {
+    fn new(
+        timestamp: usize,
+        event_type: EventType,
+        tier_id: Option<usize>,
+        agent_ids: Vec<usize>,
+    ) -> Self {
+        Self {
+            id: 0,
+            timestamp,
+            event_type,
+            tier_id,
+            agent_ids,
+            parent_ids: Vec::new(),
+        }
+    }
+
+    /// Record the events that led to this one.
+    pub fn caused_by(mut self, parent_ids: impl IntoIterator<Item = usize>) -> Self {
+        self.parent_ids.extend(parent_ids);
+        self.parent_ids.sort_unstable();
+        self.parent_ids.dedup();
+        self
+    }
+
+    pub fn launch(timestamp: usize, tier_id: usize, agent_id: usize, operator_id: usize) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Launch { operator_id },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn collision(timestamp: usize, tier_id: usize, agent_ids: Vec<usize>, damage: f64) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Collision { damage },
+            Some(tier_id),
+            agent_ids,
+        )
+    }
+
+    pub fn conjunction_warning(
+        timestamp: usize,
+        tier_id: usize,
+        agent_ids: Vec<usize>,
+        miss_distance_km: f64,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::ConjunctionWarning { miss_distance_km },
+            Some(tier_id),
+            agent_ids,
+        )
+    }
+
+    pub fn avoidance_maneuver(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        miss_distance_km: f64,
+        collision_avoided: bool,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::AvoidanceManeuver {
+                miss_distance_km,
+                collision_avoided,
+            },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn deorbit(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        reason: DeorbitReason,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Deorbit { reason },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn natural_reentry(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        mass_kg: f64,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::NaturalReentry { mass_kg },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn cascading_failure(
+        timestamp: usize,
+        tier_id: usize,
+        agent_ids: Vec<usize>,
+        congestion: f64,
+        damage: f64,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::CascadingFailure { congestion, damage },
+            Some(tier_id),
+            agent_ids,
+        )
+    }
+
+    pub fn spillover(
+        timestamp: usize,
+        from_tier: usize,
+        to_tier: usize,
+        agent_ids: Vec<usize>,
+        damage: f64,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Spillover { from_tier, damage },
+            Some(to_tier),
+            agent_ids,
+        )
+    }
+
+    pub fn fragmentation(
+        timestamp: usize,
+        tier_id: usize,
+        parent_ids: Vec<usize>,
+        fragments: usize,
+        mass_kg: f64,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Fragmentation { fragments, mass_kg },
+            Some(tier_id),
+            parent_ids,
+        )
+    }
+
+    pub fn migration(
+        timestamp: usize,
+        agent_id: usize,
+        from_tier: usize,
+        to_tier: usize,
+        cause: MigrationCause,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Migration {
+                from_tier,
+                to_tier,
+                cause,
+            },
+            Some(to_tier),
+            vec![agent_id],
+        )
+    }
+
+    pub fn subsystem_failure(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        subsystem: Subsystem,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::SubsystemFailure { subsystem },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn lifecycle_transition(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        from: LifecycleState,
+        to: LifecycleState,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::LifecycleTransition { from, to },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn compliance_violation(
+        timestamp: usize,
+        tier_id: usize,
+        agent_id: usize,
+        rule: ComplianceRule,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::ComplianceViolation { rule },
+            Some(tier_id),
+            vec![agent_id],
+        )
+    }
+
+    pub fn policy_change(
+        timestamp: usize,
+        field: String,
+        old_value: Value,
+        new_value: Value,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::PolicyChange {
+                field,
+                old_value,
+                new_value,
+            },
+            None,
+            Vec::new(),
+        )
+    }
+
+    pub fn space_weather(timestamp: usize, solar_flux: f64, activity: SolarActivity) -> Self {
+        Self::new(
+            timestamp,
+            EventType::SpaceWeather {
+                solar_flux,
+                activity,
+            },
+            None,
+            Vec::new(),
+        )
+    }
+
+    pub fn report_issued(timestamp: usize, stability_class: String, utilization: f64) -> Self {
+        Self::new(
+            timestamp,
+            EventType::ReportIssued {
+                stability_class,
+                utilization,
+            },
+            None,
+            Vec::new(),
+        )
+    }
+
+    pub fn intervention(
+        timestamp: usize,
+        tier_id: Option<usize>,
+        agent_ids: Vec<usize>,
+        description: String,
+    ) -> Self {
+        Self::new(
+            timestamp,
+            EventType::Intervention { description },
+            tier_id,
+            agent_ids,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_events_serialize_with_type_tag() {
+        let event = Event::fragmentation(4, 1, vec![7, 8], 12, 350.0);
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(
+            value["event_type"],
+            json!({"type": "fragmentation", "fragments": 12, "mass_kg": 350.0})
+        );
+        assert_eq!(value["tier_id"], json!(1));
+
+        let change =
+            Event::policy_change(9, "policy.launch_rate".to_string(), json!(0.1), json!(0.2));
+        let round_trip: Event =
+            serde_json::from_str(&serde_json::to_string(&change).unwrap()).unwrap();
+        assert!(serde_json::to_value(&change)
+            .unwrap()
+            .get("tier_id")
+            .is_none());
+        assert_eq!(round_trip.tier_id, None);
+        assert!(matches!(
+            round_trip.event_type,
+            EventType::PolicyChange { ref field, ref new_value, .. } if field == "policy.launch_rate" && *new_value == json!(0.2)
+        ));
+    }
+}
@@ -0,0 +1,295 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How a tier's collision probability grows with its utilization.
+pub trait FailureRateModel {
+    /// Factor the base failure rate is multiplied by at `utilization`, in a
+    /// tier that counts as congested above `threshold`.
+    fn multiplier(&self, utilization: f64, threshold: f64) -> f64;
+
+    /// Per-agent, per-step collision probability before it is capped at 1.
+    fn rate(&self, base: f64, utilization: f64, threshold: f64) -> f64 {
+        base * self.multiplier(utilization, threshold)
+    }
+
+    /// Describe why the curve cannot be evaluated, if it is malformed.
+    fn structural_error(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// `1 + scale · excess^exponent`, where `excess` is the utilization above
+/// the congestion threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PowerLaw {
+    #[schemars(range(min = 0.0))]
+    pub scale: f64,
+    #[schemars(range(min = 0.0))]
+    pub exponent: f64,
+}
+
+impl FailureRateModel for PowerLaw {
+    fn multiplier(&self, utilization: f64, threshold: f64) -> f64 {
+        1.0 + self.scale * excess(utilization, threshold).powf(self.exponent)
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        if self.scale.is_finite()
+            && self.scale >= 0.0
+            && self.exponent.is_finite()
+            && self.exponent > 0.0
+        {
+            None
+        } else {
+            Some("power law needs finite scale >= 0 and exponent > 0")
+        }
+    }
+}
+
+/// `exp(growth · excess)`: the rate multiplies by e for every `1 / growth`
+/// of utilization above the congestion threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Exponential {
+    #[schemars(range(min = 0.0))]
+    pub growth: f64,
+}
+
+impl FailureRateModel for Exponential {
+    fn multiplier(&self, utilization: f64, threshold: f64) -> f64 {
+        (self.growth * excess(utilization, threshold)).exp()
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        if self.growth.is_finite() && self.growth >= 0.0 {
+            None
+        } else {
+            Some("exponential growth must be a finite number >= 0")
+        }
+    }
+}
+
+/// An S-curve rising from 1 to `max_multiplier`, steepest at `midpoint`
+/// utilization (the congestion threshold if omitted). Unlike the other
+/// curves it already rises slightly below the threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Logistic {
+    #[schemars(range(min = 1.0))]
+    pub max_multiplier: f64,
+    #[schemars(range(min = 0.0))]
+    pub steepness: f64,
+    #[serde(default)]
+    pub midpoint: Option<f64>,
+}
+
+impl FailureRateModel for Logistic {
+    fn multiplier(&self, utilization: f64, threshold: f64) -> f64 {
+        let midpoint = self.midpoint.unwrap_or(threshold);
+        let share = 1.0 / (1.0 + (-self.steepness * (utilization - midpoint)).exp());
+        1.0 + (self.max_multiplier - 1.0) * share
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        if self.max_multiplier.is_finite()
+            && self.max_multiplier >= 1.0
+            && self.steepness.is_finite()
+            && self.steepness > 0.0
+            && self.midpoint.is_none_or(f64::is_finite)
+        {
+            None
+        } else {
+            Some("logistic curve needs finite max_multiplier >= 1, steepness > 0 and midpoint")
+        }
+    }
+}
+
+/// Linear interpolation between `[utilization, multiplier]` points, held
+/// constant below the first and above the last.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PiecewiseLinear {
+    pub points: Vec<(f64, f64)>,
+}
+
+impl FailureRateModel for PiecewiseLinear {
+    fn multiplier(&self, utilization: f64, _threshold: f64) -> f64 {
+        let Some(&(u0, m0)) = self.points.first() else {
+            return 1.0;
+        };
+        if utilization <= u0 {
+            return m0;
+        }
+        for pair in self.points.windows(2) {
+            let ((ua, ma), (ub, mb)) = (pair[0], pair[1]);
+            if utilization <= ub {
+                if ub == ua {
+                    return mb;
+                }
+                return ma + (mb - ma) * (utilization - ua) / (ub - ua);
+            }
+        }
+        self.points[self.points.len() - 1].1
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        points_error(&self.points)
+    }
+}
+
+/// Measured `[utilization, multiplier]` rows. Each row applies from its
+/// utilization up to the next; below the first row the multiplier is 1.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Tabulated {
+    pub rows: Vec<(f64, f64)>,
+}
+
+impl FailureRateModel for Tabulated {
+    fn multiplier(&self, utilization: f64, _threshold: f64) -> f64 {
+        self.rows
+            .iter()
+            .take_while(|(u, _)| *u <= utilization)
+            .last()
+            .map_or(1.0, |(_, m)| *m)
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        points_error(&self.rows)
+    }
+}
+
+/// The failure-rate curve of a tier, as chosen in its configuration.
+/// Defaults to the power law `1 + 10 · excess²`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FailureCurve {
+    PowerLaw(PowerLaw),
+    Exponential(Exponential),
+    Logistic(Logistic),
+    PiecewiseLinear(PiecewiseLinear),
+    Tabulated(Tabulated),
+}
+
+impl FailureCurve {
+    fn model(&self) -> &dyn FailureRateModel {
+        match self {
+            FailureCurve::PowerLaw(curve) => curve,
+            FailureCurve::Exponential(curve) => curve,
+            FailureCurve::Logistic(curve) => curve,
+            FailureCurve::PiecewiseLinear(curve) => curve,
+            FailureCurve::Tabulated(curve) => curve,
+        }
+    }
+}
+
+impl Default for FailureCurve {
+    fn default() -> Self {
+        FailureCurve::PowerLaw(PowerLaw {
+            scale: 10.0,
+            exponent: 2.0,
+        })
+    }
+}
+
+impl FailureRateModel for FailureCurve {
+    fn multiplier(&self, utilization: f64, threshold: f64) -> f64 {
+        self.model().multiplier(utilization, threshold)
+    }
+
+    fn structural_error(&self) -> Option<&'static str> {
+        self.model().structural_error()
+    }
+}
+
+fn excess(utilization: f64, threshold: f64) -> f64 {
+    (utilization - threshold).max(0.0)
+}
+
+fn points_error(points: &[(f64, f64)]) -> Option<&'static str> {
+    if points.is_empty() {
+        Some("curve needs at least one point")
+    } else if points.iter().any(|(u, _)| !u.is_finite()) {
+        Some("curve utilizations must be finite numbers")
+    } else if points.windows(2).any(|w| w[1].0 < w[0].0) {
+        Some("curve points must be in utilization order")
+    } else if points.iter().any(|(_, m)| !(m.is_finite() && *m >= 0.0)) {
+        Some("curve multipliers must be finite numbers >= 0")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_quadratic_excess() {
+        let curve = FailureCurve::default();
+        assert_eq!(curve.rate(0.001, 0.5, 0.7), 0.001);
+        assert!((curve.rate(0.001, 0.9, 0.7) - 0.001 * 1.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_curves_from_config() {
+        let parse = |json: &str| serde_json::from_str::<FailureCurve>(json).unwrap();
+
+        let exponential = parse(r#"{"type": "exponential", "growth": 2.0}"#);
+        assert!((exponential.multiplier(1.2, 0.7) - 1.0_f64.exp()).abs() < 1e-12);
+
+        let logistic = parse(r#"{"type": "logistic", "max_multiplier": 5.0, "steepness": 20.0}"#);
+        assert_eq!(logistic.multiplier(0.7, 0.7), 3.0);
+        assert!(logistic.multiplier(2.0, 0.7) > 4.99);
+
+        let piecewise =
+            parse(r#"{"type": "piecewise_linear", "points": [[0.5, 1.0], [1.0, 3.0]]}"#);
+        assert_eq!(piecewise.multiplier(0.75, 0.7), 2.0);
+        assert_eq!(piecewise.multiplier(1.5, 0.7), 3.0);
+
+        let table = parse(r#"{"type": "tabulated", "rows": [[0.6, 2.0], [0.9, 8.0]]}"#);
+        assert_eq!(table.multiplier(0.5, 0.7), 1.0);
+        assert_eq!(table.multiplier(0.89, 0.7), 2.0);
+        assert_eq!(table.multiplier(0.9, 0.7), 8.0);
+
+        let unordered = parse(r#"{"type": "tabulated", "rows": [[0.9, 8.0], [0.6, 2.0]]}"#);
+        assert!(unordered.structural_error().is_some());
+    }
+
+    #[test]
+    fn test_non_finite_parameters_are_rejected() {
+        let curves = [
+            FailureCurve::PowerLaw(PowerLaw {
+                scale: f64::INFINITY,
+                exponent: 2.0,
+            }),
+            FailureCurve::PowerLaw(PowerLaw {
+                scale: 10.0,
+                exponent: f64::NAN,
+            }),
+            FailureCurve::Exponential(Exponential {
+                growth: f64::INFINITY,
+            }),
+            FailureCurve::Logistic(Logistic {
+                max_multiplier: f64::INFINITY,
+                steepness: 20.0,
+                midpoint: None,
+            }),
+            FailureCurve::Logistic(Logistic {
+                max_multiplier: 5.0,
+                steepness: 20.0,
+                midpoint: Some(f64::NAN),
+            }),
+            FailureCurve::PiecewiseLinear(PiecewiseLinear {
+                points: vec![(f64::NAN, 1.0), (1.0, 3.0)],
+            }),
+            FailureCurve::Tabulated(Tabulated {
+                rows: vec![(0.6, 2.0), (f64::INFINITY, 8.0)],
+            }),
+        ];
+        for curve in curves {
+            assert!(curve.structural_error().is_some(), "{:?}", curve);
+        }
+    }
+}
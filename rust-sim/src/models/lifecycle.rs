@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Where an object is in its life, from launch to leaving orbit.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleState {
+    /// On its way to the target orbit.
+    Launching,
+    /// In orbit and being checked out before service.
+    Commissioning,
+    #[default]
+    Operational,
+    /// Past its design life and awaiting disposal.
+    EndOfLife,
+    /// Lowering its orbit to re-enter under control.
+    Deorbiting,
+    /// Uncontrolled but still in orbit, and still a collision hazard.
+    Derelict,
+    /// Gone from orbit: re-entered or destroyed.
+    Removed,
+}
+
+impl LifecycleState {
+    pub const ALL: [LifecycleState; 7] = [
+        LifecycleState::Launching,
+        LifecycleState::Commissioning,
+        LifecycleState::Operational,
+        LifecycleState::EndOfLife,
+        LifecycleState::Deorbiting,
+        LifecycleState::Derelict,
+        LifecycleState::Removed,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LifecycleState::Launching => "launching",
+            LifecycleState::Commissioning => "commissioning",
+            LifecycleState::Operational => "operational",
+            LifecycleState::EndOfLife => "end_of_life",
+            LifecycleState::Deorbiting => "deorbiting",
+            LifecycleState::Derelict => "derelict",
+            LifecycleState::Removed => "removed",
+        }
+    }
+
+    /// Whether an object in this state can move to `to`. Objects only move
+    /// forward through their life; any object still in orbit can lose
+    /// control or be removed.
+    pub fn can_transition_to(&self, to: LifecycleState) -> bool {
+        use LifecycleState::*;
+        match (self, to) {
+            (Removed, _) => false,
+            (_, Removed) => true,
+            (Derelict, _) => false,
+            (_, Derelict) => true,
+            (Launching, Commissioning) => true,
+            (Commissioning, Operational) => true,
+            (Operational, EndOfLife) => true,
+            (Operational | EndOfLife, Deorbiting) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the operator can order the object to change orbit.
+    pub fn accepts_commands(&self) -> bool {
+        matches!(
+            self,
+            LifecycleState::Operational | LifecycleState::EndOfLife
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legal_transitions() {
+        use LifecycleState::*;
+        assert!(Launching.can_transition_to(Commissioning));
+        assert!(Operational.can_transition_to(Deorbiting));
+        assert!(Deorbiting.can_transition_to(Derelict));
+        assert!(Derelict.can_transition_to(Removed));
+
+        assert!(!Launching.can_transition_to(Operational));
+        assert!(!Operational.can_transition_to(Commissioning));
+        assert!(!Derelict.can_transition_to(Operational));
+        assert!(!Removed.can_transition_to(Removed));
+        assert!(!Operational.can_transition_to(Operational));
+    }
+}
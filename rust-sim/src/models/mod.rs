@@ -1,3 +1,9 @@
-pub mod agent;
-pub mod capacity_tier;
-pub mod event;
\ No newline at end of file
+pub mod adjacency;
+pub mod agent;
+pub mod capacity_tier;
+pub mod event;
+pub mod failure_rate;
+pub mod lifecycle;
+pub mod object_kind;
+pub mod operator;
+pub mod subsystem;
@@ -0,0 +1,116 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+
+/// What an orbiting object is, which decides how it can behave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectKind {
+    #[default]
+    ActiveSatellite,
+    DefunctSatellite,
+    RocketBody,
+    DebrisFragment,
+}
+
+impl ObjectKind {
+    pub const ALL: [ObjectKind; 4] = [
+        ObjectKind::ActiveSatellite,
+        ObjectKind::DefunctSatellite,
+        ObjectKind::RocketBody,
+        ObjectKind::DebrisFragment,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ObjectKind::ActiveSatellite => "active_satellite",
+            ObjectKind::DefunctSatellite => "defunct_satellite",
+            ObjectKind::RocketBody => "rocket_body",
+            ObjectKind::DebrisFragment => "debris_fragment",
+        }
+    }
+
+    /// Only working satellites have propulsion and control.
+    pub fn can_maneuver(&self) -> bool {
+        matches!(self, ObjectKind::ActiveSatellite)
+    }
+
+    /// Whether mitigation policy applies to the object. Spent upper stages
+    /// still fall under passivation rules; dead hardware and debris do not.
+    pub fn subject_to_compliance(&self) -> bool {
+        matches!(self, ObjectKind::ActiveSatellite | ObjectKind::RocketBody)
+    }
+}
+
+/// Object counts broken down by kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KindCounts {
+    pub active_satellite: usize,
+    pub defunct_satellite: usize,
+    pub rocket_body: usize,
+    pub debris_fragment: usize,
+}
+
+impl KindCounts {
+    pub fn get(&self, kind: ObjectKind) -> usize {
+        match kind {
+            ObjectKind::ActiveSatellite => self.active_satellite,
+            ObjectKind::DefunctSatellite => self.defunct_satellite,
+            ObjectKind::RocketBody => self.rocket_body,
+            ObjectKind::DebrisFragment => self.debris_fragment,
+        }
+    }
+
+    fn get_mut(&mut self, kind: ObjectKind) -> &mut usize {
+        match kind {
+            ObjectKind::ActiveSatellite => &mut self.active_satellite,
+            ObjectKind::DefunctSatellite => &mut self.defunct_satellite,
+            ObjectKind::RocketBody => &mut self.rocket_body,
+            ObjectKind::DebrisFragment => &mut self.debris_fragment,
+        }
+    }
+
+    pub fn increment(&mut self, kind: ObjectKind) {
+        *self.get_mut(kind) += 1;
+    }
+
+    pub fn decrement(&mut self, kind: ObjectKind) {
+        let count = self.get_mut(kind);
+        *count = count.saturating_sub(1);
+    }
+
+    pub fn total(&self) -> usize {
+        ObjectKind::ALL.iter().map(|&k| self.get(k)).sum()
+    }
+}
+
+impl Add for KindCounts {
+    type Output = KindCounts;
+
+    fn add(self, other: KindCounts) -> KindCounts {
+        KindCounts {
+            active_satellite: self.active_satellite + other.active_satellite,
+            defunct_satellite: self.defunct_satellite + other.defunct_satellite,
+            rocket_body: self.rocket_body + other.rocket_body,
+            debris_fragment: self.debris_fragment + other.debris_fragment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_counts() {
+        let mut counts = KindCounts::default();
+        counts.increment(ObjectKind::DebrisFragment);
+        counts.increment(ObjectKind::DebrisFragment);
+        counts.increment(ObjectKind::RocketBody);
+        counts.decrement(ObjectKind::ActiveSatellite);
+        assert_eq!(counts.get(ObjectKind::DebrisFragment), 2);
+        assert_eq!(counts.get(ObjectKind::ActiveSatellite), 0);
+        assert_eq!(counts.total(), 3);
+        assert_eq!((counts + counts).total(), 6);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::config::parameters::{OperatorConfig, SimulationConfig};
+use crate::config::schedule::Schedule;
+use crate::models::capacity_tier::CapacityTier;
+use crate::models::event::{Event, EventType};
+use crate::models::object_kind::KindCounts;
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+
+/// An organisation owning a fleet of objects and launching new ones.
+#[derive(Debug, Clone)]
+pub struct Operator {
+    pub id: usize,
+    pub name: String,
+    pub initial_share: f64,
+    pub launch_rate: Schedule,
+    pub compliance_rate: Schedule,
+    pub risk_appetite: f64,
+}
+
+impl Operator {
+    /// Name of the operator used when the configuration lists none.
+    pub const UNAFFILIATED: &'static str = "unaffiliated";
+
+    pub fn from_config(id: usize, config: &OperatorConfig, defaults: &SimulationConfig) -> Self {
+        Self {
+            id,
+            name: config.name.clone(),
+            initial_share: config.initial_share,
+            launch_rate: config
+                .launch_rate
+                .clone()
+                .unwrap_or_else(|| defaults.policy.launch_rate.clone()),
+            compliance_rate: config
+                .compliance_rate
+                .clone()
+                .unwrap_or_else(|| defaults.policy.compliance_rate.clone()),
+            risk_appetite: config.risk_appetite,
+        }
+    }
+
+    /// The operators of a configuration, or a single unaffiliated operator
+    /// with the policy-wide rates and no tier preference.
+    pub fn all_from_config(config: &SimulationConfig) -> Vec<Operator> {
+        if config.operators.is_empty() {
+            return vec![Operator {
+                id: 0,
+                name: Self::UNAFFILIATED.to_string(),
+                initial_share: 1.0,
+                launch_rate: config.policy.launch_rate.clone(),
+                compliance_rate: config.policy.compliance_rate.clone(),
+                risk_appetite: 1.0,
+            }];
+        }
+        config
+            .operators
+            .iter()
+            .enumerate()
+            .map(|(i, o)| Operator::from_config(i, o, config))
+            .collect()
+    }
+
+    /// Target tier for a launch: any tier with probability `risk_appetite`,
+    /// otherwise the least utilized one.
+    pub fn choose_tier(&self, tiers: &[CapacityTier], rng: &mut SimulationRng) -> usize {
+        if rng.gen_bool(self.risk_appetite) {
+            rng.gen_range(0..tiers.len())
+        } else {
+            tiers
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.utilization().total_cmp(&b.utilization()))
+                .map_or(0, |(i, _)| i)
+        }
+    }
+}
+
+/// Pick an owner for an initial object in proportion to the operators' shares.
+pub fn choose_operator(operators: &[Operator], rng: &mut SimulationRng) -> usize {
+    if operators.len() == 1 {
+        return 0;
+    }
+    let total: f64 = operators.iter().map(|o| o.initial_share).sum();
+    let mut draw = rng.gen_range(0.0..total);
+    for operator in operators {
+        if draw < operator.initial_share {
+            return operator.id;
+        }
+        draw -= operator.initial_share;
+    }
+    operators.len() - 1
+}
+
+/// Fleet and event tallies of one operator up to some timestep.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OperatorStats {
+    pub operator_id: usize,
+    pub name: String,
+    /// Objects currently in orbit, by kind.
+    pub fleet: KindCounts,
+    pub launches: usize,
+    pub collisions: usize,
+    pub cascading_failures: usize,
+    pub fragmentations: usize,
+    pub deorbits: usize,
+    pub reentries: usize,
+    pub migrations: usize,
+    #[serde(default)]
+    pub subsystem_failures: usize,
+    #[serde(default)]
+    pub avoidance_maneuvers: usize,
+    #[serde(default)]
+    pub compliance_violations: usize,
+    #[serde(default)]
+    pub spillovers: usize,
+}
+
+impl OperatorStats {
+    pub fn new(operator: &Operator) -> Self {
+        Self {
+            operator_id: operator.id,
+            name: operator.name.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Count an event this operator's objects took part in.
+    pub fn record(&mut self, event: &Event) {
+        match event.event_type {
+            EventType::Launch { .. } => self.launches += 1,
+            EventType::Collision { .. } => self.collisions += 1,
+            EventType::CascadingFailure { .. } => self.cascading_failures += 1,
+            EventType::Spillover { .. } => self.spillovers += 1,
+            EventType::Fragmentation { .. } => self.fragmentations += 1,
+            EventType::Deorbit { .. } => self.deorbits += 1,
+            EventType::NaturalReentry { .. } => self.reentries += 1,
+            EventType::Migration { .. } => self.migrations += 1,
+            EventType::SubsystemFailure { .. } => self.subsystem_failures += 1,
+            EventType::AvoidanceManeuver { .. } => self.avoidance_maneuvers += 1,
+            EventType::ComplianceViolation { .. } => self.compliance_violations += 1,
+            EventType::ConjunctionWarning { .. }
+            | EventType::LifecycleTransition { .. }
+            | EventType::PolicyChange { .. }
+            | EventType::SpaceWeather { .. }
+            | EventType::ReportIssued { .. }
+            | EventType::Intervention { .. } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators_inherit_policy_rates() {
+        let mut config = SimulationConfig::default();
+        assert_eq!(
+            Operator::all_from_config(&config)[0].name,
+            Operator::UNAFFILIATED
+        );
+
+        config.operators = serde_json::from_str(
+            r#"[{"name": "A", "launch_rate": 0.5}, {"name": "B", "compliance_rate": 0.1, "risk_appetite": 0.0}]"#,
+        )
+        .unwrap();
+        let operators = Operator::all_from_config(&config);
+        assert_eq!(operators[0].launch_rate, 0.5.into());
+        assert_eq!(operators[0].compliance_rate, config.policy.compliance_rate);
+        assert_eq!(operators[1].launch_rate, config.policy.launch_rate);
+
+        let mut tiers: Vec<CapacityTier> = config
+            .capacity_tiers
+            .iter()
+            .enumerate()
+            .map(|(i, t)| CapacityTier::from_config(i, t))
+            .collect();
+        tiers[0].current_count = 150;
+        tiers[2].current_count = 150;
+        let mut rng = SimulationRng::new(Some(3));
+        assert!((0..20).all(|_| operators[1].choose_tier(&tiers, &mut rng) == 1));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+
+/// Health below which a subsystem no longer works.
+pub const FUNCTIONAL_THRESHOLD: f64 = 0.5;
+
+/// A satellite subsystem that can fail independently of the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Subsystem {
+    /// Needed to maneuver: avoid, raise orbit, keep station or deorbit.
+    Propulsion,
+    /// Needed to receive commands and report status.
+    Comms,
+    /// Needed for everything; a satellite without power is dead.
+    Power,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 3] = [Subsystem::Propulsion, Subsystem::Comms, Subsystem::Power];
+}
+
+/// Health of each subsystem, from 1 (nominal) down to 0 (destroyed).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SubsystemHealth {
+    pub propulsion: f64,
+    pub comms: f64,
+    pub power: f64,
+}
+
+impl Default for SubsystemHealth {
+    fn default() -> Self {
+        Self {
+            propulsion: 1.0,
+            comms: 1.0,
+            power: 1.0,
+        }
+    }
+}
+
+impl SubsystemHealth {
+    pub fn get(&self, subsystem: Subsystem) -> f64 {
+        match subsystem {
+            Subsystem::Propulsion => self.propulsion,
+            Subsystem::Comms => self.comms,
+            Subsystem::Power => self.power,
+        }
+    }
+
+    fn get_mut(&mut self, subsystem: Subsystem) -> &mut f64 {
+        match subsystem {
+            Subsystem::Propulsion => &mut self.propulsion,
+            Subsystem::Comms => &mut self.comms,
+            Subsystem::Power => &mut self.power,
+        }
+    }
+
+    pub fn is_functional(&self, subsystem: Subsystem) -> bool {
+        self.get(subsystem) > FUNCTIONAL_THRESHOLD
+    }
+
+    /// Knock out a subsystem completely. Returns true if it was working.
+    pub fn fail(&mut self, subsystem: Subsystem) -> bool {
+        let was_functional = self.is_functional(subsystem);
+        *self.get_mut(subsystem) = 0.0;
+        was_functional
+    }
+
+    /// Apply impact damage to one randomly chosen subsystem. Returns the
+    /// subsystem if the hit made it stop working.
+    pub fn damage_random(&mut self, damage: f64, rng: &mut SimulationRng) -> Option<Subsystem> {
+        let subsystem = Subsystem::ALL[rng.gen_range(0..Subsystem::ALL.len())];
+        let was_functional = self.is_functional(subsystem);
+        let health = self.get_mut(subsystem);
+        *health = (*health - damage).max(0.0);
+        (was_functional && !self.is_functional(subsystem)).then_some(subsystem)
+    }
+}
@@ -0,0 +1,167 @@
+use crate::models::event::Event;
+use std::collections::BTreeSet;
+use std::error::Error;
+
+/// The causal links between the events of a run. Every edge runs from an
+/// earlier event to a later one, so the graph is a DAG.
+pub struct EventGraph<'a> {
+    events: &'a [Event],
+    children: Vec<Vec<usize>>,
+    /// Longest chain of causes leading to each event; roots have depth 0.
+    depths: Vec<usize>,
+}
+
+impl<'a> EventGraph<'a> {
+    /// Build the graph of an event log. Fails if the ids are not the
+    /// positions in the log or an event names a later one as its parent.
+    pub fn new(events: &'a [Event]) -> Result<Self, Box<dyn Error>> {
+        let mut children = vec![Vec::new(); events.len()];
+        let mut depths = vec![0; events.len()];
+        for (i, event) in events.iter().enumerate() {
+            if event.id != i {
+                return Err(format!("event at position {} has id {}", i, event.id).into());
+            }
+            for &parent in &event.parent_ids {
+                if parent >= i {
+                    return Err(
+                        format!("event {} names later event {} as its parent", i, parent).into(),
+                    );
+                }
+                children[parent].push(i);
+                depths[i] = depths[i].max(depths[parent] + 1);
+            }
+        }
+        Ok(Self {
+            events,
+            children,
+            depths,
+        })
+    }
+
+    /// Number of events in the graph.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn event(&self, id: usize) -> &Event {
+        &self.events[id]
+    }
+
+    pub fn children(&self, id: usize) -> &[usize] {
+        &self.children[id]
+    }
+
+    /// Events that caused others but were not caused by anything logged.
+    pub fn roots(&self) -> Vec<usize> {
+        (0..self.events.len())
+            .filter(|&id| self.events[id].parent_ids.is_empty() && !self.children[id].is_empty())
+            .collect()
+    }
+
+    /// Whether the event is part of any causal chain.
+    pub fn is_linked(&self, id: usize) -> bool {
+        !self.events[id].parent_ids.is_empty() || !self.children[id].is_empty()
+    }
+
+    /// The uncaused events that `id` ultimately traces back to; an event
+    /// with no parents is its own root cause.
+    pub fn root_causes(&self, id: usize) -> Vec<usize> {
+        let mut roots = BTreeSet::new();
+        let mut stack = vec![id];
+        let mut seen = BTreeSet::new();
+        while let Some(current) = stack.pop() {
+            if !seen.insert(current) {
+                continue;
+            }
+            let parents = &self.events[current].parent_ids;
+            if parents.is_empty() {
+                roots.insert(current);
+            }
+            stack.extend(parents);
+        }
+        roots.into_iter().collect()
+    }
+
+    /// Number of links in the longest chain of causes ending at `id`.
+    pub fn depth(&self, id: usize) -> usize {
+        self.depths[id]
+    }
+
+    /// Length of the longest causal chain in the run.
+    pub fn max_depth(&self) -> usize {
+        self.depths.iter().copied().max().unwrap_or(0)
+    }
+
+    /// The event and everything it led to, directly or not, in log order.
+    pub fn descendants(&self, id: usize) -> Vec<usize> {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            if seen.insert(current) {
+                stack.extend(&self.children[current]);
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Health lost across the chain started by each root, largest first.
+    /// An event reached from several roots counts toward each of them.
+    pub fn damage_by_root(&self) -> Vec<(usize, f64)> {
+        let mut totals: Vec<(usize, f64)> = self
+            .roots()
+            .into_iter()
+            .map(|root| {
+                let damage = self
+                    .descendants(root)
+                    .iter()
+                    .map(|&id| self.events[id].event_type.damage())
+                    .sum();
+                (root, damage)
+            })
+            .collect();
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventLog;
+
+    #[test]
+    fn test_chain_queries() {
+        let mut events = Vec::new();
+        let warning = events.log(Event::conjunction_warning(0, 0, vec![1, 2], 0.1));
+        let collision = events.log(Event::collision(0, 0, vec![1, 2], 0.4).caused_by([warning]));
+        let breakup =
+            events.log(Event::fragmentation(0, 0, vec![1], 10, 50.0).caused_by([collision]));
+        events.log(Event::launch(1, 0, 2, 0));
+        let cascade =
+            events.log(Event::cascading_failure(1, 0, vec![3, 4], 0.9, 1.1).caused_by([breakup]));
+        let other = events.log(Event::collision(1, 0, vec![5], 0.2));
+        let joint = events
+            .log(Event::cascading_failure(2, 0, vec![6], 0.9, 0.5).caused_by([cascade, other]));
+
+        let graph = EventGraph::new(&events).unwrap();
+        assert_eq!(graph.roots(), vec![warning, other]);
+        assert_eq!(graph.root_causes(joint), vec![warning, other]);
+        assert_eq!(graph.root_causes(warning), vec![warning]);
+        assert_eq!(graph.depth(cascade), 3);
+        assert_eq!(graph.depth(joint), 4);
+        assert_eq!(graph.max_depth(), 4);
+        assert!(!graph.is_linked(3));
+
+        let damage = graph.damage_by_root();
+        assert_eq!(damage[0].0, warning);
+        assert!((damage[0].1 - 2.0).abs() < 1e-9);
+        assert!((damage[1].1 - 0.7).abs() < 1e-9);
+
+        events[joint].parent_ids = vec![joint + 1];
+        assert!(EventGraph::new(&events).is_err());
+    }
+}
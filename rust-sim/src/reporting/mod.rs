@@ -1,2 +1,4 @@
-pub mod language;
-pub mod templates;
\ No newline at end of file
+pub mod causality;
+pub mod language;
+pub mod replay;
+pub mod templates;
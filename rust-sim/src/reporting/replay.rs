@@ -0,0 +1,136 @@
+use crate::config::parameters::ReportingConfig;
+use crate::models::event::{Event, EventType};
+use crate::reporting::language::LanguageGenerator;
+use crate::simulation::engine::StatusReport;
+use std::error::Error;
+
+/// Number of events in the reporting window ending at `timestamp`, not
+/// counting earlier reports.
+pub fn count_recent_events(events: &[Event], timestamp: usize, report_interval: usize) -> usize {
+    let start = timestamp.saturating_sub(report_interval);
+    events
+        .iter()
+        .filter(|e| e.timestamp >= start && e.timestamp <= timestamp)
+        .filter(|e| !matches!(e.event_type, EventType::ReportIssued { .. }))
+        .count()
+}
+
+/// Number of hazard events (conjunction warnings, collisions, cascading
+/// failures, spillovers and fragmentations) in the same window. Reports
+/// describe this count as conjunction activity, so bookkeeping such as
+/// migrations or lifecycle transitions is left out.
+pub fn count_recent_hazards(events: &[Event], timestamp: usize, report_interval: usize) -> usize {
+    let start = timestamp.saturating_sub(report_interval);
+    events
+        .iter()
+        .filter(|e| e.timestamp >= start && e.timestamp <= timestamp)
+        .filter(|e| is_hazard(&e.event_type))
+        .count()
+}
+
+fn is_hazard(event_type: &EventType) -> bool {
+    matches!(
+        event_type,
+        EventType::ConjunctionWarning { .. }
+            | EventType::Collision { .. }
+            | EventType::CascadingFailure { .. }
+            | EventType::Spillover { .. }
+            | EventType::Fragmentation { .. }
+    )
+}
+
+/// Regenerate report text from saved reports and a saved event log, e.g. with
+/// hedging switched off. Ground truth is kept; event counts are recomputed
+/// from the log.
+pub fn replay_reports(
+    reports: &[StatusReport],
+    events: &[Event],
+    config: &ReportingConfig,
+) -> Result<Vec<StatusReport>, Box<dyn Error>> {
+    let language = LanguageGenerator::new(config.clone());
+
+    reports
+        .iter()
+        .map(|report| {
+            if report.tiers.is_empty() {
+                return Err(format!(
+                    "report at t={} has no tier snapshot and cannot be replayed",
+                    report.timestamp
+                )
+                .into());
+            }
+            let recent_events =
+                count_recent_events(events, report.timestamp, config.report_interval);
+            let hazard_events =
+                count_recent_hazards(events, report.timestamp, config.report_interval);
+            let report_text = language.generate_report(
+                &report.tiers,
+                report.ground_truth_utilization,
+                report.ground_truth_risk,
+                hazard_events,
+                &report.stability_class,
+            );
+            Ok(StatusReport {
+                report_text,
+                recent_events,
+                hazard_events,
+                ..report.clone()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::parameters::SimulationConfig;
+    use crate::models::event::{ComplianceRule, MigrationCause, SolarActivity};
+    use crate::models::lifecycle::LifecycleState;
+    use crate::models::subsystem::Subsystem;
+    use crate::simulation::engine::SimulationEngine;
+
+    #[test]
+    fn test_only_hazard_events_are_counted() {
+        let mut events = vec![
+            Event::conjunction_warning(5, 0, vec![1, 2], 0.1),
+            Event::collision(5, 0, vec![1, 2], 0.4),
+            Event::fragmentation(6, 0, vec![1], 10, 50.0),
+            Event::cascading_failure(7, 0, vec![3], 0.9, 1.1),
+            Event::spillover(7, 0, 1, vec![4], 0.5),
+            Event::collision(20, 0, vec![5], 0.2),
+        ];
+        assert_eq!(count_recent_events(&events, 10, 10), 5);
+        assert_eq!(count_recent_hazards(&events, 10, 10), 5);
+
+        events.extend([
+            Event::lifecycle_transition(
+                6,
+                0,
+                1,
+                LifecycleState::Operational,
+                LifecycleState::EndOfLife,
+            ),
+            Event::migration(6, 2, 0, 1, MigrationCause::Decay),
+            Event::subsystem_failure(7, 0, 3, Subsystem::Propulsion),
+            Event::compliance_violation(8, 0, 4, ComplianceRule::PostMissionDisposal),
+            Event::space_weather(8, 180.0, SolarActivity::High),
+            Event::report_issued(10, "stable".to_string(), 0.5),
+        ]);
+        assert_eq!(count_recent_events(&events, 10, 10), 10);
+        assert_eq!(count_recent_hazards(&events, 10, 10), 5);
+    }
+
+    #[test]
+    fn test_replay_matches_original_run() {
+        let config = SimulationConfig::default().with_time_steps(200);
+        let reporting = config.reporting.clone();
+        let results = SimulationEngine::new(config).unwrap().run();
+
+        let replayed = replay_reports(&results.reports, &results.events, &reporting).unwrap();
+        for (original, again) in results.reports.iter().zip(&replayed) {
+            assert_eq!(original.report_text, again.report_text);
+            assert_eq!(original.recent_events, again.recent_events);
+            assert_eq!(original.hazard_events, again.hazard_events);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::config::parameters::{DragConfig, SolarCycleConfig};
+use crate::models::capacity_tier::{tier_for_altitude, CapacityTier, EARTH_RADIUS_KM};
+use crate::models::event::{EventLog, MigrationCause, SolarActivity};
+use crate::models::lifecycle::LifecycleState;
+use crate::models::{agent::Agent, event::Event};
+use crate::simulation::lifecycle::transition;
+use crate::simulation::migration::migrate;
+use std::f64::consts::PI;
+
+/// Earth's gravitational parameter, in m³/s².
+const MU_EARTH: f64 = 3.986004418e14;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const DAYS_PER_YEAR: f64 = 365.25;
+/// F10.7 flux at which the density table applies unscaled, in sfu.
+const REFERENCE_FLUX: f64 = 140.0;
+
+/// Exponential atmosphere (Vallado): base altitude in km, density at the
+/// base in kg/m³ and scale height in km, for mean solar conditions.
+const ATMOSPHERE: [(f64, f64, f64); 14] = [
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// F10.7 solar flux at `years` into the run, starting at the configured
+/// phase of the cycle.
+pub fn solar_flux(cycle: &SolarCycleConfig, years: f64) -> f64 {
+    let mean = (cycle.flux.min + cycle.flux.max) / 2.0;
+    let amplitude = (cycle.flux.max - cycle.flux.min) / 2.0;
+    mean - amplitude * (2.0 * PI * (years + cycle.phase_years) / cycle.period_years).cos()
+}
+
+/// Band of the cycle's flux range that `flux` falls in, split into thirds.
+pub fn solar_activity(cycle: &SolarCycleConfig, flux: f64) -> SolarActivity {
+    let position = (flux - cycle.flux.min) / (cycle.flux.max - cycle.flux.min);
+    if position < 1.0 / 3.0 {
+        SolarActivity::Low
+    } else if position < 2.0 / 3.0 {
+        SolarActivity::Moderate
+    } else {
+        SolarActivity::High
+    }
+}
+
+/// Atmospheric density at `altitude_km`, in kg/m³. A more active Sun heats
+/// the thermosphere, raising density roughly with the square of the flux.
+pub fn atmospheric_density(altitude_km: f64, flux: f64) -> f64 {
+    let (base, density, scale_height) = ATMOSPHERE
+        .iter()
+        .rev()
+        .find(|(base, _, _)| altitude_km >= *base)
+        .copied()
+        .unwrap_or(ATMOSPHERE[0]);
+    density * (-(altitude_km - base) / scale_height).exp() * (flux / REFERENCE_FLUX).powi(2)
+}
+
+/// Lowers orbits under drag, moves objects into the tier matching their
+/// new altitude and removes those that re-enter. Satellites that can still
+/// maneuver keep station and are unaffected.
+pub struct DragModel {
+    config: DragConfig,
+    days_per_step: f64,
+}
+
+impl DragModel {
+    pub fn new(config: DragConfig, days_per_step: f64) -> Self {
+        Self {
+            config,
+            days_per_step,
+        }
+    }
+
+    pub fn set_config(&mut self, config: DragConfig, days_per_step: f64) {
+        self.config = config;
+        self.days_per_step = days_per_step;
+    }
+
+    /// Solar flux during timestep `timestamp`.
+    pub fn flux_at(&self, timestamp: usize) -> f64 {
+        let years = timestamp as f64 * self.days_per_step / DAYS_PER_YEAR;
+        solar_flux(&self.config.solar_cycle, years)
+    }
+
+    /// Solar activity band during timestep `timestamp`.
+    pub fn activity_at(&self, timestamp: usize) -> SolarActivity {
+        solar_activity(&self.config.solar_cycle, self.flux_at(timestamp))
+    }
+
+    /// Altitude lost by one object over `days`, in km, integrated in steps
+    /// of at most a day: da/dt = -ρ · (Cd·A/m) · √(μ·a).
+    pub fn decay(&self, agent: &Agent, flux: f64, days: f64) -> f64 {
+        let ballistic = self.config.drag_coefficient * agent.area_to_mass();
+        let substeps = days.ceil().max(1.0);
+        let dt = days * SECONDS_PER_DAY / substeps;
+
+        let mut altitude = agent.altitude_km;
+        for _ in 0..substeps as usize {
+            if altitude <= self.config.reentry_altitude_km {
+                break;
+            }
+            let semi_major_axis = (EARTH_RADIUS_KM + altitude) * 1000.0;
+            let rate = atmospheric_density(altitude, flux)
+                * ballistic
+                * (MU_EARTH * semi_major_axis).sqrt();
+            altitude -= rate * dt / 1000.0;
+        }
+        agent.altitude_km - altitude
+    }
+
+    pub fn update(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        timestamp: usize,
+    ) {
+        if !self.config.enabled {
+            return;
+        }
+        let flux = self.flux_at(timestamp);
+
+        for agent in agents
+            .iter_mut()
+            .filter(|a| a.is_alive() && !a.can_maneuver())
+        {
+            agent.altitude_km -= self.decay(agent, flux, self.days_per_step);
+
+            if agent.altitude_km <= self.config.reentry_altitude_km {
+                // Cleanup releases the tier slot
+                let reentry = events.log(Event::natural_reentry(
+                    timestamp,
+                    agent.tier_id,
+                    agent.id,
+                    agent.mass_kg,
+                ));
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Removed,
+                    Some(reentry),
+                    events,
+                    timestamp,
+                );
+                continue;
+            }
+
+            let tier_id = tier_for_altitude(tiers, agent.altitude_km);
+            migrate(
+                tiers,
+                agent,
+                tier_id,
+                MigrationCause::Decay,
+                events,
+                timestamp,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+    use crate::models::object_kind::ObjectKind;
+
+    #[test]
+    fn test_solar_cycle_and_density() {
+        let cycle = SolarCycleConfig::default();
+        assert!((solar_flux(&cycle, 0.0) - 70.0).abs() < 1e-9);
+        assert!((solar_flux(&cycle, 5.5) - 200.0).abs() < 1e-9);
+        assert!(atmospheric_density(400.0, 140.0) > atmospheric_density(600.0, 140.0));
+        assert!(atmospheric_density(400.0, 200.0) > atmospheric_density(400.0, 70.0));
+        assert_eq!(solar_activity(&cycle, 70.0), SolarActivity::Low);
+        assert_eq!(solar_activity(&cycle, 200.0), SolarActivity::High);
+    }
+
+    #[test]
+    fn test_decay_depends_on_area_to_mass() {
+        let model = DragModel::new(DragConfig::default(), 7.0);
+        let satellite = Agent::new(0, 0, true).with_orbit(400.0, 53.0, 500.0, 5.0);
+        let fragment = Agent::new(1, 0, false).with_orbit(400.0, 53.0, 1.0, 0.1);
+        let high = Agent::new(2, 0, true).with_orbit(800.0, 53.0, 500.0, 5.0);
+
+        let decay = model.decay(&satellite, 140.0, 1.0);
+        // A few hundred metres per day at 400 km for a compact satellite
+        assert!(decay > 0.05 && decay < 0.5, "decay = {}", decay);
+        assert!(model.decay(&fragment, 140.0, 1.0) > decay);
+        assert!(model.decay(&high, 140.0, 1.0) < decay / 100.0);
+    }
+
+    #[test]
+    fn test_objects_migrate_and_reenter() {
+        let mut tiers = vec![
+            CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(150.0, 300.0),
+            CapacityTier::new(1, "High".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(300.0, 800.0),
+        ];
+        let mut agents = vec![
+            Agent::new(0, 1, false)
+                .with_kind(ObjectKind::DefunctSatellite)
+                .with_orbit(301.0, 53.0, 100.0, 10.0),
+            Agent::new(1, 0, false)
+                .with_kind(ObjectKind::DefunctSatellite)
+                .with_orbit(160.0, 53.0, 100.0, 10.0),
+            Agent::new(2, 1, true).with_orbit(301.0, 53.0, 100.0, 10.0),
+        ];
+        tiers[1].add_agent(agents[0].kind);
+        tiers[0].add_agent(agents[1].kind);
+        tiers[1].add_agent(agents[2].kind);
+
+        let model = DragModel::new(DragConfig::default(), 7.0);
+        let mut events = Vec::new();
+        model.update(&mut tiers, &mut agents, &mut events, 0);
+
+        assert_eq!(agents[0].tier_id, 0);
+        assert_eq!((tiers[0].current_count, tiers[1].current_count), (2, 1));
+        assert!(!agents[1].is_alive());
+        // The active satellite keeps station
+        assert_eq!(agents[2].altitude_km, 301.0);
+
+        let kinds: Vec<(&EventType, &[usize])> = events
+            .iter()
+            .map(|e| (&e.event_type, e.agent_ids.as_slice()))
+            .collect();
+        assert!(matches!(
+            kinds[0],
+            (
+                EventType::Migration {
+                    from_tier: 1,
+                    to_tier: 0,
+                    ..
+                },
+                [0]
+            )
+        ));
+        assert!(matches!(kinds[1], (EventType::NaturalReentry { .. }, [1])));
+        assert!(matches!(
+            kinds[2],
+            (
+                EventType::LifecycleTransition {
+                    to: LifecycleState::Removed,
+                    ..
+                },
+                [1]
+            )
+        ));
+    }
+}
@@ -1,95 +1,464 @@
-use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
-use crate::utils::rng::SimulationRng;
-
-pub struct SystemDynamics;
-
-impl SystemDynamics {
-    pub fn new() -> Self {
-        Self
-    }
-
-    pub fn update(
-        &self,
-        tiers: &mut [CapacityTier],
-        agents: &mut [Agent],
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        // Age all agents
-        for agent in agents.iter_mut() {
-            agent.tick();
-        }
-
-        // Check for collisions in each tier
-        for tier in tiers.iter() {
-            if tier.is_congested() {
-                self.check_collisions(tier, agents, events, timestamp, rng);
-            }
-        }
-
-        // Check for cascading failures
-        self.check_cascading_failures(tiers, agents, events, timestamp, rng);
-    }
-
-    fn check_collisions(
-        &self,
-        tier: &CapacityTier,
-        agents: &mut [Agent],
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        let failure_rate = tier.effective_failure_rate();
-        
-        for agent in agents.iter_mut() {
-            if agent.tier_id == tier.id && rng.gen_bool(failure_rate) {
-                let damage = rng.gen_range(0.1..0.5);
-                agent.apply_damage(damage);
-                
-                events.push(Event::collision(
-                    timestamp,
-                    tier.id,
-                    vec![agent.id],
-                    damage,
-                ));
-            }
-        }
-    }
-
-    fn check_cascading_failures(
-        &self,
-        tiers: &[CapacityTier],
-        agents: &mut [Agent],
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        for tier in tiers.iter() {
-            let severity = tier.congestion_severity();
-            
-            // Cascading failures become likely at high congestion
-            if severity > 0.8 && rng.gen_bool(severity * 0.05) {
-                let affected: Vec<usize> = agents
-                    .iter()
-                    .filter(|a| a.tier_id == tier.id)
-                    .take(5)
-                    .map(|a| a.id)
-                    .collect();
-
-                for agent in agents.iter_mut() {
-                    if affected.contains(&agent.id) {
-                        agent.apply_damage(rng.gen_range(0.3..0.8));
-                    }
-                }
-
-                events.push(Event::cascading_failure(
-                    timestamp,
-                    tier.id,
-                    affected,
-                    severity,
-                ));
-            }
-        }
-    }
-}
\ No newline at end of file
+use crate::config::parameters::{
+    ConjunctionConfig, SimulationConfig, SpilloverConfig, SubsystemConfig,
+};
+use crate::models::adjacency::TierAdjacency;
+use crate::models::event::EventLog;
+use crate::models::object_kind::ObjectKind;
+use crate::models::subsystem::Subsystem;
+use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
+use crate::simulation::drag::DragModel;
+use crate::simulation::fragmentation::{Breakup, FragmentationModel};
+use crate::simulation::lifecycle::LifecycleModel;
+use crate::utils::ids::IdAllocator;
+use crate::utils::rng::SimulationRng;
+use rand::distributions::WeightedIndex;
+use rand_distr::Poisson;
+use std::collections::HashMap;
+
+pub struct SystemDynamics {
+    fragmentation: FragmentationModel,
+    drag: DragModel,
+    lifecycle: LifecycleModel,
+    subsystems: SubsystemConfig,
+    conjunctions: ConjunctionConfig,
+    spillover: SpilloverConfig,
+    /// Spillover links between tiers; see [`SystemDynamics::link_tiers`].
+    adjacency: TierAdjacency,
+    days_per_step: f64,
+    /// Tier and event id of each fragmentation in the previous step, whose
+    /// debris can set off cascades in the next.
+    recent_fragmentations: Vec<(usize, usize)>,
+}
+
+impl SystemDynamics {
+    pub fn new(config: &SimulationConfig) -> Self {
+        Self {
+            fragmentation: FragmentationModel::new(config.fragmentation.clone()),
+            drag: DragModel::new(config.drag.clone(), config.days_per_step),
+            lifecycle: LifecycleModel::new(config.lifecycle.clone(), config.days_per_step),
+            subsystems: config.subsystems.clone(),
+            conjunctions: config.conjunctions.clone(),
+            spillover: config.spillover.clone(),
+            adjacency: TierAdjacency::default(),
+            days_per_step: config.days_per_step,
+            recent_fragmentations: Vec::new(),
+        }
+    }
+
+    /// Pick up physics settings changed mid-run.
+    pub fn set_config(&mut self, config: &SimulationConfig) {
+        self.fragmentation.set_config(config.fragmentation.clone());
+        self.drag
+            .set_config(config.drag.clone(), config.days_per_step);
+        self.lifecycle
+            .set_config(config.lifecycle.clone(), config.days_per_step);
+        self.subsystems = config.subsystems.clone();
+        self.conjunctions = config.conjunctions.clone();
+        self.spillover = config.spillover.clone();
+        self.days_per_step = config.days_per_step;
+    }
+
+    /// Rebuild the spillover links from the current tiers and spillover
+    /// settings. Call once the tiers exist and again whenever tiers are
+    /// added, renamed or moved, or the spillover settings change.
+    pub fn link_tiers(&mut self, tiers: &[CapacityTier]) {
+        self.adjacency = TierAdjacency::build(tiers, &self.spillover);
+    }
+
+    /// Solar flux during timestep `timestamp`.
+    pub fn solar_flux(&self, timestamp: usize) -> f64 {
+        self.drag.flux_at(timestamp)
+    }
+
+    pub fn update(
+        &mut self,
+        tiers: &mut [CapacityTier],
+        agents: &mut Vec<Agent>,
+        events: &mut Vec<Event>,
+        ids: &mut IdAllocator,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        // Age all agents
+        for agent in agents.iter_mut() {
+            agent.tick();
+        }
+
+        // Satellites move on through commissioning, service and deorbit
+        self.lifecycle.advance(tiers, agents, events, timestamp);
+
+        // Event that last damaged each agent, so its failure can be traced back
+        let mut causes = HashMap::new();
+
+        // Subsystems of working satellites wear out at random
+        self.check_subsystem_failures(agents, &mut causes, events, timestamp, rng);
+
+        // Log shifts in solar activity, which drive drag
+        let activity = self.drag.activity_at(timestamp);
+        if timestamp == 0 || self.drag.activity_at(timestamp - 1) != activity {
+            events.log(Event::space_weather(
+                timestamp,
+                self.drag.flux_at(timestamp),
+                activity,
+            ));
+        }
+
+        // Drag lowers orbits; decayed objects change tier or re-enter
+        self.drag.update(tiers, agents, events, timestamp);
+
+        // Check for collisions in each tier
+        let mut breakups = Vec::new();
+        for tier in tiers.iter() {
+            if tier.is_congested() {
+                self.check_collisions(tier, agents, events, &mut breakups, timestamp, rng);
+            }
+        }
+
+        // Check for cascading failures, which may spill into linked tiers
+        self.check_cascading_failures(tiers, agents, events, &mut breakups, timestamp, rng);
+
+        // Damaged objects shed debris into this and neighbouring tiers
+        self.recent_fragmentations.clear();
+        for breakup in &breakups {
+            for &index in &breakup.parents {
+                causes.insert(agents[index].id, breakup.cause);
+            }
+            if let Some(id) = self
+                .fragmentation
+                .fragment(breakup, tiers, agents, events, ids, rng)
+            {
+                self.recent_fragmentations.push((breakup.tier_id, id));
+            }
+        }
+
+        // Destroyed objects are removed; badly damaged or unpowered
+        // satellites lose control and stay in orbit as derelicts
+        self.lifecycle
+            .settle_failures(tiers, agents, &causes, events, timestamp);
+    }
+
+    /// Screen a tier for close approaches between pairs of objects. Each
+    /// object's chance of being in a collision is the tier's failure rate
+    /// scaled by its cross-section, and each collision involves two objects,
+    /// so the expected number of collisions is half the sum of those chances.
+    /// Screening raises `warnings_per_collision` times as many warnings, each
+    /// pairing two objects in proportion to their cross-sections.
+    fn check_collisions(
+        &self,
+        tier: &CapacityTier,
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        breakups: &mut Vec<Breakup>,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        let failure_rate = tier.effective_failure_rate();
+        let screening = &self.conjunctions;
+        let collision_radius = screening.collision_radius_km();
+
+        let candidates: Vec<usize> = (0..agents.len())
+            .filter(|&i| agents[i].tier_id == tier.id && agents[i].is_alive())
+            .collect();
+        let probabilities: Vec<f64> = candidates
+            .iter()
+            .map(|&i| (failure_rate * agents[i].cross_section_factor()).min(1.0))
+            .collect();
+        // A pair needs two objects that can be struck
+        if probabilities.iter().filter(|&&p| p > 0.0).count() < 2 {
+            return;
+        }
+        let expected_collisions = probabilities.iter().sum::<f64>() / 2.0;
+        let expected_warnings = probabilities
+            .iter()
+            .map(|p| (p * screening.warnings_per_collision).min(1.0))
+            .sum::<f64>()
+            / 2.0;
+        let (Ok(approaches), Ok(mut encounter)) = (
+            Poisson::new(expected_warnings),
+            WeightedIndex::new(&probabilities),
+        ) else {
+            return;
+        };
+
+        for _ in 0..rng.sample(&approaches) as usize {
+            let drawn = rng.sample(&encounter);
+            // Draw the partner with the first object set aside; the guard
+            // above leaves another object with positive weight
+            if encounter.update_weights(&[(drawn, &0.0)]).is_err() {
+                return;
+            }
+            let partner = rng.sample(&encounter);
+            if encounter
+                .update_weights(&[(drawn, &probabilities[drawn])])
+                .is_err()
+            {
+                return;
+            }
+            let (first, second) = (candidates[drawn], candidates[partner]);
+            // Objects destroyed earlier in the step are no longer screened
+            if !agents[first].is_alive() || !agents[second].is_alive() {
+                continue;
+            }
+            let pair = vec![agents[first].id, agents[second].id];
+
+            let collides = rng.gen_bool((expected_collisions / expected_warnings).min(1.0));
+            let u: f64 = rng.gen_range(0.0..1.0);
+            let miss_distance = if collides {
+                u * collision_radius
+            } else {
+                collision_radius + u * (screening.screening_radius_km - collision_radius)
+            };
+            let warning_id = events.log(Event::conjunction_warning(
+                timestamp,
+                tier.id,
+                pair.clone(),
+                miss_distance,
+            ));
+
+            // An operator still in control of either object moves it out of
+            // the way of a close approach
+            if miss_distance < screening.maneuver_threshold_km {
+                if let Some(&index) = [first, second]
+                    .iter()
+                    .find(|&&i| agents[i].accepts_commands())
+                {
+                    let maneuver = Event::avoidance_maneuver(
+                        timestamp,
+                        tier.id,
+                        agents[index].id,
+                        miss_distance,
+                        collides,
+                    );
+                    events.log(maneuver.caused_by([warning_id]));
+                    continue;
+                }
+            }
+
+            if collides {
+                let damages = [rng.gen_range(0.1..0.5), rng.gen_range(0.1..0.5)];
+                for (&index, &damage) in [first, second].iter().zip(&damages) {
+                    agents[index].apply_damage(damage);
+                }
+                let collision = Event::collision(timestamp, tier.id, pair, damages.iter().sum());
+                let collision_id = events.log(collision.caused_by([warning_id]));
+
+                let mut breakup = Breakup::new(timestamp, tier.id, collision_id);
+                for (&index, &damage) in [first, second].iter().zip(&damages) {
+                    let agent = &mut agents[index];
+                    // The impact may also knock out one of a satellite's subsystems
+                    if agent.kind == ObjectKind::ActiveSatellite && agent.is_alive() {
+                        if let Some(subsystem) = agent.subsystems.damage_random(damage, rng) {
+                            let failure =
+                                Event::subsystem_failure(timestamp, tier.id, agent.id, subsystem);
+                            events.log(failure.caused_by([collision_id]));
+                        }
+                    }
+                    breakup.add(index, agent, damage);
+                }
+                breakups.push(breakup);
+            }
+        }
+    }
+
+    /// Each working subsystem of a live active satellite fails with the
+    /// probability implied by its constant hazard rate over one timestep.
+    fn check_subsystem_failures(
+        &self,
+        agents: &mut [Agent],
+        causes: &mut HashMap<usize, usize>,
+        events: &mut Vec<Event>,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        let years = self.days_per_step / 365.25;
+        for agent in agents
+            .iter_mut()
+            .filter(|a| a.kind == ObjectKind::ActiveSatellite && a.is_alive())
+        {
+            for subsystem in Subsystem::ALL {
+                let probability = 1.0 - (-self.subsystems.hazard_per_year(subsystem) * years).exp();
+                if agent.subsystems.is_functional(subsystem) && rng.gen_bool(probability) {
+                    agent.subsystems.fail(subsystem);
+                    let id = events.log(Event::subsystem_failure(
+                        timestamp,
+                        agent.tier_id,
+                        agent.id,
+                        subsystem,
+                    ));
+                    causes.insert(agent.id, id);
+                }
+            }
+        }
+    }
+
+    /// A cascade is set off by this step's collisions and last step's
+    /// fragmentations in the same tier, which are logged as its parents.
+    /// It may then spill into linked tiers, hitting objects there with
+    /// scaled-down damage; spillovers do not spread any further that step.
+    fn check_cascading_failures(
+        &self,
+        tiers: &[CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        breakups: &mut Vec<Breakup>,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        for tier in tiers.iter() {
+            let severity = tier.congestion_severity();
+
+            // Cascading failures become likely at high congestion
+            if severity > 0.8 && rng.gen_bool((severity * 0.05).min(1.0)) {
+                let (affected, damages) = strike(tier.id, agents, 1.0, rng);
+                let triggers: Vec<usize> = breakups
+                    .iter()
+                    .filter(|b| b.tier_id == tier.id)
+                    .map(|b| b.cause)
+                    .chain(
+                        self.recent_fragmentations
+                            .iter()
+                            .filter(|(t, _)| *t == tier.id)
+                            .map(|(_, id)| *id),
+                    )
+                    .collect();
+                let cascade = Event::cascading_failure(
+                    timestamp,
+                    tier.id,
+                    affected.iter().map(|&i| agents[i].id).collect(),
+                    severity,
+                    damages.iter().sum(),
+                );
+                let cascade_id = events.log(cascade.caused_by(triggers));
+                breakups.push(apply_strike(
+                    timestamp, tier.id, cascade_id, &affected, &damages, agents,
+                ));
+
+                for &(neighbor, probability) in self.adjacency.neighbors(tier.id) {
+                    if !rng.gen_bool(probability) {
+                        continue;
+                    }
+                    let (affected, damages) =
+                        strike(neighbor, agents, self.spillover.damage_scale, rng);
+                    if affected.is_empty() {
+                        continue;
+                    }
+                    let spillover = Event::spillover(
+                        timestamp,
+                        tier.id,
+                        neighbor,
+                        affected.iter().map(|&i| agents[i].id).collect(),
+                        damages.iter().sum(),
+                    );
+                    let spillover_id = events.log(spillover.caused_by([cascade_id]));
+                    breakups.push(apply_strike(
+                        timestamp,
+                        neighbor,
+                        spillover_id,
+                        &affected,
+                        &damages,
+                        agents,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Pick up to five live objects in tier `tier_id` at random and draw the
+/// damage each takes from a cascade, scaled by `scale`.
+fn strike(
+    tier_id: usize,
+    agents: &[Agent],
+    scale: f64,
+    rng: &mut SimulationRng,
+) -> (Vec<usize>, Vec<f64>) {
+    let candidates: Vec<usize> = agents
+        .iter()
+        .enumerate()
+        .filter(|(_, a)| a.tier_id == tier_id && a.is_alive())
+        .map(|(i, _)| i)
+        .collect();
+    let affected = rng.choose_multiple(&candidates, 5);
+    let damages = affected
+        .iter()
+        .map(|_| rng.gen_range(0.3..0.8) * scale)
+        .collect();
+    (affected, damages)
+}
+
+/// Damage the struck objects, collecting the debris they shed.
+fn apply_strike(
+    timestamp: usize,
+    tier_id: usize,
+    cause: usize,
+    affected: &[usize],
+    damages: &[f64],
+    agents: &mut [Agent],
+) -> Breakup {
+    let mut breakup = Breakup::new(timestamp, tier_id, cause);
+    for (&index, &damage) in affected.iter().zip(damages) {
+        agents[index].apply_damage(damage);
+        breakup.add(index, &mut agents[index], damage);
+    }
+    breakup
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+    use crate::models::lifecycle::LifecycleState;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_collisions_involve_pairs() {
+        let dynamics = SystemDynamics::new(&SimulationConfig::default());
+        let mut tier = CapacityTier::new(0, "Low".to_string(), 10, 0.7, 0.05);
+        let mut agents: Vec<Agent> = (0..20)
+            .map(|i| Agent::new(i, 0, false).with_kind(ObjectKind::RocketBody))
+            .collect();
+        for agent in &agents {
+            tier.add_agent(agent.kind);
+        }
+
+        let mut events = Vec::new();
+        let mut breakups = Vec::new();
+        let mut rng = SimulationRng::new(Some(3));
+        dynamics.check_collisions(&tier, &mut agents, &mut events, &mut breakups, 0, &mut rng);
+
+        let collisions: Vec<&Event> = events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Collision { .. }))
+            .collect();
+        assert!(!collisions.is_empty());
+        assert_eq!(collisions.len(), breakups.len());
+        for (collision, breakup) in collisions.iter().zip(&breakups) {
+            assert_eq!(collision.agent_ids.len(), 2);
+            assert_ne!(collision.agent_ids[0], collision.agent_ids[1]);
+            assert_eq!(breakup.parents.len(), 2);
+            for &id in &collision.agent_ids {
+                assert!(agents[id].health < 1.0);
+            }
+            let warning = &events[collision.parent_ids[0]];
+            assert_eq!(warning.agent_ids, collision.agent_ids);
+        }
+    }
+
+    #[test]
+    fn test_cascade_victims_are_sampled_from_the_whole_tier() {
+        let mut agents: Vec<Agent> = (0..20).map(|i| Agent::new(i, i % 2, false)).collect();
+        agents[4].lifecycle = LifecycleState::Removed;
+        let mut rng = SimulationRng::new(Some(3));
+
+        let mut struck = BTreeSet::new();
+        for _ in 0..20 {
+            let (affected, damages) = strike(0, &agents, 1.0, &mut rng);
+            assert_eq!(affected.len(), 5);
+            assert_eq!(damages.len(), 5);
+            assert_eq!(affected.iter().collect::<BTreeSet<_>>().len(), 5);
+            struck.extend(affected);
+        }
+        assert!(struck.iter().all(|&i| i % 2 == 0 && i != 4));
+        assert_eq!(struck.len(), 9);
+    }
+}
@@ -1,167 +1,771 @@
-use crate::config::parameters::SimulationConfig;
-use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
-use crate::reporting::language::LanguageGenerator;
-use crate::simulation::dynamics::SystemDynamics;
-use crate::simulation::policy::PolicyEngine;
-use crate::utils::rng::SimulationRng;
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatusReport {
-    pub timestamp: usize,
-    pub report_text: String,
-    pub ground_truth_utilization: f64,
-    pub ground_truth_risk: f64,
-    pub agent_count: usize,
-    pub stability_class: String,
-    pub recent_events: usize,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SimulationResults {
-    pub reports: Vec<StatusReport>,
-    pub events: Vec<Event>,
-    pub final_agent_count: usize,
-    pub cascading_failures: usize,
-}
-
-pub struct SimulationEngine {
-    config: SimulationConfig,
-    tiers: Vec<CapacityTier>,
-    agents: Vec<Agent>,
-    events: Vec<Event>,
-    dynamics: SystemDynamics,
-    policy: PolicyEngine,
-    language: LanguageGenerator,
-    rng: SimulationRng,
-    current_time: usize,
-}
-
-impl SimulationEngine {
-    pub fn new(config: SimulationConfig) -> Self {
-        let mut rng = SimulationRng::new(config.random_seed);
-
-        // Initialize capacity tiers
-        let tiers: Vec<CapacityTier> = config
-            .capacity_tiers
-            .iter()
-            .enumerate()
-            .map(|(i, tc)| {
-                CapacityTier::new(
-                    i,
-                    tc.name.clone(),
-                    tc.max_capacity,
-                    tc.congestion_threshold,
-                    tc.failure_base_rate,
-                )
-            })
-            .collect();
-
-        // Initialize agents
-        let mut agents = Vec::new();
-        for i in 0..config.initial_agents {
-            let tier_id = i % tiers.len();
-            let compliance = rng.gen_bool(config.policy.compliance_rate);
-            agents.push(Agent::new(i, tier_id, compliance));
-        }
-
-        // Update tier counts
-        let mut tiers = tiers;
-        for agent in &agents {
-            tiers[agent.tier_id].add_agent();
-        }
-
-        Self {
-            dynamics: SystemDynamics::new(),
-            policy: PolicyEngine::new(config.policy.clone()),
-            language: LanguageGenerator::new(config.reporting.clone()),
-            rng,
-            config,
-            tiers,
-            agents,
-            events: Vec::new(),
-            current_time: 0,
-        }
-    }
-
-    pub fn run(&mut self) -> SimulationResults {
-        let mut reports = Vec::new();
-
-        for t in 0..self.config.time_steps {
-            self.current_time = t;
-
-            // Simulate dynamics
-            self.dynamics.update(&mut self.tiers, &mut self.agents, &mut self.events, t, &mut self.rng);
-
-            // Apply policy
-            self.policy.apply(&mut self.tiers, &mut self.agents, &mut self.events, t, &mut self.rng);
-
-            // Generate reports
-            if t % self.config.reporting.report_interval == 0 {
-                let report = self.generate_report(t);
-                reports.push(report);
-            }
-
-            // Cleanup dead agents
-            self.cleanup_agents();
-        }
-
-        let cascading_failures = self.events.iter()
-            .filter(|e| matches!(e.event_type, crate::models::event::EventType::CascadingFailure))
-            .count();
-
-        SimulationResults {
-            reports,
-            events: self.events.clone(),
-            final_agent_count: self.agents.len(),
-            cascading_failures,
-        }
-    }
-
-    fn generate_report(&self, timestamp: usize) -> StatusReport {
-        let avg_util = self.tiers.iter().map(|t| t.utilization()).sum::<f64>() / self.tiers.len() as f64;
-        let avg_risk = self.tiers.iter().map(|t| t.effective_failure_rate()).sum::<f64>() / self.tiers.len() as f64;
-        
-        let stability_class = if avg_util < 0.6 {
-            "Stable"
-        } else if avg_util < 0.85 {
-            "Degrading"
-        } else {
-            "Critical"
-        }.to_string();
-
-        let recent_events = self.events.iter()
-            .filter(|e| e.timestamp >= timestamp.saturating_sub(self.config.reporting.report_interval))
-            .count();
-
-        let report_text = self.language.generate_report(
-            &self.tiers,
-            avg_util,
-            avg_risk,
-            recent_events,
-            &stability_class,
-        );
-
-        StatusReport {
-            timestamp,
-            report_text,
-            ground_truth_utilization: avg_util,
-            ground_truth_risk: avg_risk,
-            agent_count: self.agents.len(),
-            stability_class,
-            recent_events,
-        }
-    }
-
-    fn cleanup_agents(&mut self) {
-        let initial_count = self.agents.len();
-        self.agents.retain(|a| a.is_alive());
-        let removed = initial_count - self.agents.len();
-        
-        for _ in 0..removed {
-            for tier in &mut self.tiers {
-                tier.remove_agent();
-            }
-        }
-    }
-}
\ No newline at end of file
+use crate::config::interventions::{Intervention, InterventionAction};
+use crate::config::parameters::SimulationConfig;
+use crate::config::validation::ValidationErrors;
+use crate::models::capacity_tier::tier_for_altitude;
+use crate::models::event::{DeorbitReason, EventLog, MigrationCause};
+use crate::models::lifecycle::LifecycleState;
+use crate::models::object_kind::KindCounts;
+use crate::models::operator::{choose_operator, Operator, OperatorStats};
+use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
+use crate::reporting::language::LanguageGenerator;
+use crate::reporting::replay::{count_recent_events, count_recent_hazards};
+use crate::simulation::dynamics::SystemDynamics;
+use crate::simulation::lifecycle::transition;
+use crate::simulation::migration::migrate;
+use crate::simulation::policy::PolicyEngine;
+use crate::utils::ids::IdAllocator;
+use crate::utils::rng::SimulationRng;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusReport {
+    pub timestamp: usize,
+    pub report_text: String,
+    pub ground_truth_utilization: f64,
+    pub ground_truth_risk: f64,
+    pub agent_count: usize,
+    pub stability_class: String,
+    pub recent_events: usize,
+    /// Hazard events in the reporting window: conjunction warnings,
+    /// collisions, cascading failures, spillovers and fragmentations.
+    #[serde(default)]
+    pub hazard_events: usize,
+    /// Objects in orbit by kind, summed over tiers.
+    #[serde(default)]
+    pub kind_counts: KindCounts,
+    /// Objects in orbit by lifecycle state; removed objects are not counted.
+    #[serde(default)]
+    pub lifecycle_counts: BTreeMap<LifecycleState, usize>,
+    /// Objects removed from orbit so far, by re-entry or destruction.
+    #[serde(default)]
+    pub removed_objects: usize,
+    /// F10.7 solar flux driving atmospheric drag, in sfu.
+    #[serde(default)]
+    pub solar_flux: f64,
+    /// Fleets and cumulative event counts per operator.
+    #[serde(default)]
+    pub operators: Vec<OperatorStats>,
+    /// Tier state at report time, kept so reports can be regenerated later.
+    #[serde(default)]
+    pub tiers: Vec<CapacityTier>,
+}
+
+/// Nominal and debris-degraded capacity of one tier at the end of a timestep.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TierCapacity {
+    pub timestamp: usize,
+    pub tier_id: usize,
+    pub tier: String,
+    pub nominal_capacity: usize,
+    pub effective_capacity: f64,
+    pub debris_fragments: usize,
+    pub utilization: f64,
+}
+
+impl TierCapacity {
+    fn of(tier: &CapacityTier, timestamp: usize) -> Self {
+        Self {
+            timestamp,
+            tier_id: tier.id,
+            tier: tier.name.clone(),
+            nominal_capacity: tier.max_capacity,
+            effective_capacity: tier.effective_capacity(),
+            debris_fragments: tier.kind_counts.debris_fragment,
+            utilization: tier.utilization(),
+        }
+    }
+
+    /// Capacity lost to debris.
+    pub fn gap(&self) -> f64 {
+        self.nominal_capacity as f64 - self.effective_capacity
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulationResults {
+    pub reports: Vec<StatusReport>,
+    pub events: Vec<Event>,
+    pub final_agent_count: usize,
+    pub cascading_failures: usize,
+    /// Final fleets and event counts per operator.
+    #[serde(default)]
+    pub operators: Vec<OperatorStats>,
+    /// Capacity of every tier at every timestep.
+    #[serde(default)]
+    pub capacity: Vec<TierCapacity>,
+}
+
+pub struct SimulationEngine {
+    config: SimulationConfig,
+    tiers: Vec<CapacityTier>,
+    agents: Vec<Agent>,
+    events: Vec<Event>,
+    dynamics: SystemDynamics,
+    policy: PolicyEngine,
+    language: LanguageGenerator,
+    rng: SimulationRng,
+    /// Source of every object id: initial population, launches and debris.
+    ids: IdAllocator,
+    current_time: usize,
+    interventions: Vec<Intervention>,
+    next_intervention: usize,
+    operators: Vec<Operator>,
+    operator_stats: Vec<OperatorStats>,
+    tallied_events: usize,
+    removed_objects: usize,
+}
+
+impl SimulationEngine {
+    pub fn new(config: SimulationConfig) -> Result<Self, ValidationErrors> {
+        config.validate()?;
+
+        let mut rng = SimulationRng::new(config.random_seed);
+
+        // Initialize capacity tiers
+        let tiers: Vec<CapacityTier> = config
+            .capacity_tiers
+            .iter()
+            .enumerate()
+            .map(|(i, tc)| CapacityTier::from_config(i, tc))
+            .collect();
+
+        // Initialize agents, each owned by an operator. The initial
+        // population is spread evenly through its design life.
+        let operators = Operator::all_from_config(&config);
+        let design_life = config.lifecycle.design_life_steps(config.days_per_step);
+        let mut ids = IdAllocator::new();
+        let mut agents = Vec::new();
+        for i in 0..config.initial_agents {
+            let tier = &tiers[i % tiers.len()];
+            let kind = config.object_mix.sample_kind(&mut rng);
+            let operator = &operators[choose_operator(&operators, &mut rng)];
+            let compliance = rng.gen_bool(operator.compliance_rate.value_at(0));
+            let properties = config.properties_for(kind);
+            let mut agent =
+                Agent::spawn(ids.allocate(), tier, kind, compliance, properties, &mut rng)
+                    .with_operator(operator.id);
+            agent.age = rng.gen_range(0..design_life);
+            agents.push(agent);
+        }
+        let operator_stats = operators.iter().map(OperatorStats::new).collect();
+
+        // Interventions are applied in timestep order; ties keep file order
+        let mut interventions = config.interventions.clone();
+        interventions.sort_by_key(|i| i.at);
+
+        // Update tier counts
+        let mut tiers = tiers;
+        for agent in &agents {
+            tiers[agent.tier_id].add_agent(agent.kind);
+        }
+
+        let mut dynamics = SystemDynamics::new(&config);
+        dynamics.link_tiers(&tiers);
+
+        Ok(Self {
+            dynamics,
+            policy: PolicyEngine::new(&config),
+            language: LanguageGenerator::new(config.reporting.clone()),
+            rng,
+            ids,
+            config,
+            tiers,
+            agents,
+            events: Vec::new(),
+            current_time: 0,
+            interventions,
+            next_intervention: 0,
+            operators,
+            operator_stats,
+            tallied_events: 0,
+            removed_objects: 0,
+        })
+    }
+
+    pub fn run(&mut self) -> SimulationResults {
+        let mut reports = Vec::new();
+        let mut capacity = Vec::new();
+
+        for t in 0..self.config.time_steps {
+            self.current_time = t;
+
+            // Apply scheduled interventions
+            self.apply_interventions(t);
+
+            // Advance time-varying tier parameters
+            for tier in &mut self.tiers {
+                tier.update_schedules(t);
+            }
+
+            // Simulate dynamics
+            self.dynamics.update(
+                &mut self.tiers,
+                &mut self.agents,
+                &mut self.events,
+                &mut self.ids,
+                t,
+                &mut self.rng,
+            );
+
+            // Apply policy
+            self.policy.apply(
+                &mut self.tiers,
+                &mut self.agents,
+                &mut self.events,
+                &mut self.ids,
+                t,
+                &mut self.rng,
+            );
+            debug_assert!(
+                self.ids_are_unique(),
+                "object ids must be unique and allocated by the engine"
+            );
+
+            self.tally_operator_events();
+
+            // Generate reports
+            if t % self.config.reporting.report_interval == 0 {
+                let report = self.generate_report(t);
+                self.events.log(Event::report_issued(
+                    t,
+                    report.stability_class.clone(),
+                    report.ground_truth_utilization,
+                ));
+                reports.push(report);
+            }
+
+            // Cleanup dead agents
+            self.cleanup_agents();
+
+            capacity.extend(self.tiers.iter().map(|tier| TierCapacity::of(tier, t)));
+        }
+
+        let cascading_failures = self
+            .events
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.event_type,
+                    crate::models::event::EventType::CascadingFailure { .. }
+                )
+            })
+            .count();
+
+        SimulationResults {
+            reports,
+            events: self.events.clone(),
+            final_agent_count: self.agents.len(),
+            cascading_failures,
+            operators: self.operator_snapshot(),
+            capacity,
+        }
+    }
+
+    fn apply_interventions(&mut self, timestamp: usize) {
+        while let Some(intervention) = self.interventions.get(self.next_intervention) {
+            if intervention.at > timestamp {
+                break;
+            }
+            let action = intervention.action.clone();
+            self.next_intervention += 1;
+            let mut description = action.describe();
+
+            let (tier_id, agent_ids) = match &action {
+                InterventionAction::SetParameter { field, value } => {
+                    // Validation replayed this change, so a failure here is a
+                    // bug; record it rather than abort the run
+                    if let Err(e) = self.set_parameter(field, value.clone(), timestamp) {
+                        description = format!("{} (not applied: {})", description, e);
+                    }
+                    (None, Vec::new())
+                }
+                InterventionAction::ForceDeorbit {
+                    tier,
+                    fraction,
+                    non_compliant_only,
+                } => {
+                    let tier_id = tier
+                        .as_ref()
+                        .and_then(|name| self.tiers.iter().position(|t| &t.name == name));
+                    let ids =
+                        self.force_deorbit(tier_id, *fraction, *non_compliant_only, timestamp);
+                    (tier_id, ids)
+                }
+                InterventionAction::AddTier { tier } => {
+                    let id = self.tiers.len();
+                    self.config.capacity_tiers.push(tier.clone());
+                    let mut new_tier = CapacityTier::from_config(id, tier);
+                    new_tier.update_schedules(timestamp);
+                    self.tiers.push(new_tier);
+                    self.rebin_agents(timestamp);
+                    self.dynamics.link_tiers(&self.tiers);
+                    (Some(id), Vec::new())
+                }
+            };
+
+            self.events.log(Event::intervention(
+                timestamp,
+                tier_id,
+                agent_ids,
+                description,
+            ));
+        }
+    }
+
+    /// Set a config field and propagate it to every component. The config is
+    /// left unchanged if the path or value is rejected.
+    fn set_parameter(
+        &mut self,
+        field: &str,
+        value: serde_json::Value,
+        timestamp: usize,
+    ) -> Result<(), Box<dyn Error>> {
+        let old_value = self.config.get_path(field)?;
+        self.config.set_path(field, value)?;
+        let new_value = self.config.get_path(field)?;
+        self.events.log(Event::policy_change(
+            timestamp,
+            field.to_string(),
+            old_value,
+            new_value,
+        ));
+
+        self.policy.set_config(&self.config);
+        self.dynamics.set_config(&self.config);
+        self.operators = Operator::all_from_config(&self.config);
+        for operator in self.operators.iter().skip(self.operator_stats.len()) {
+            self.operator_stats.push(OperatorStats::new(operator));
+        }
+        self.language = LanguageGenerator::new(self.config.reporting.clone());
+        let bands = |tiers: &[CapacityTier]| -> Vec<(f64, f64)> {
+            tiers
+                .iter()
+                .map(|t| (t.min_altitude_km, t.max_altitude_km))
+                .collect()
+        };
+        let old_bands = bands(&self.tiers);
+        for (tier, tier_config) in self.tiers.iter_mut().zip(&self.config.capacity_tiers) {
+            tier.apply_config(tier_config);
+        }
+        if bands(&self.tiers) != old_bands {
+            self.rebin_agents(timestamp);
+        }
+        // Links follow tier names and band order
+        if field.starts_with("capacity_tiers") || field.starts_with("spillover") {
+            self.dynamics.link_tiers(&self.tiers);
+        }
+        Ok(())
+    }
+
+    /// Move every object in orbit into the tier whose band now contains its
+    /// altitude, after a tier was added or a band changed.
+    fn rebin_agents(&mut self, timestamp: usize) {
+        for agent in self
+            .agents
+            .iter_mut()
+            .filter(|a| a.lifecycle != LifecycleState::Removed)
+        {
+            let tier_id = tier_for_altitude(&self.tiers, agent.altitude_km);
+            migrate(
+                &mut self.tiers,
+                agent,
+                tier_id,
+                MigrationCause::BandChange,
+                &mut self.events,
+                timestamp,
+            );
+        }
+    }
+
+    fn force_deorbit(
+        &mut self,
+        tier_id: Option<usize>,
+        fraction: f64,
+        non_compliant_only: bool,
+        timestamp: usize,
+    ) -> Vec<usize> {
+        let mut candidates: Vec<usize> = self
+            .agents
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.accepts_commands())
+            .filter(|(_, a)| tier_id.is_none_or(|id| a.tier_id == id))
+            .filter(|(_, a)| !non_compliant_only || !a.compliance)
+            .map(|(i, _)| i)
+            .collect();
+
+        // Partial Fisher-Yates shuffle to pick the deorbited agents
+        let count = (fraction * candidates.len() as f64).round() as usize;
+        for i in 0..count {
+            let j = self.rng.gen_range(i..candidates.len());
+            candidates.swap(i, j);
+        }
+
+        candidates[..count]
+            .iter()
+            .map(|&i| {
+                let agent = &mut self.agents[i];
+                let deorbit = Event::deorbit(
+                    timestamp,
+                    agent.tier_id,
+                    agent.id,
+                    DeorbitReason::Intervention,
+                );
+                let deorbit = self.events.log(deorbit);
+                transition(
+                    &mut self.tiers,
+                    agent,
+                    LifecycleState::Deorbiting,
+                    Some(deorbit),
+                    &mut self.events,
+                    timestamp,
+                );
+                agent.id
+            })
+            .collect()
+    }
+
+    /// Attribute events logged since the last call to the operators whose
+    /// objects took part. Runs before cleanup, so every object is present.
+    fn tally_operator_events(&mut self) {
+        let owners: HashMap<usize, usize> =
+            self.agents.iter().map(|a| (a.id, a.operator_id)).collect();
+        for event in &self.events[self.tallied_events..] {
+            let involved: BTreeSet<usize> = event
+                .agent_ids
+                .iter()
+                .filter_map(|id| owners.get(id).copied())
+                .collect();
+            for operator_id in involved {
+                if let Some(stats) = self.operator_stats.get_mut(operator_id) {
+                    stats.record(event);
+                }
+            }
+        }
+        self.tallied_events = self.events.len();
+    }
+
+    /// Event tallies so far, with each operator's current fleet.
+    fn operator_snapshot(&self) -> Vec<OperatorStats> {
+        let mut stats = self.operator_stats.clone();
+        for s in &mut stats {
+            s.fleet = KindCounts::default();
+        }
+        for agent in &self.agents {
+            if let Some(s) = stats.get_mut(agent.operator_id) {
+                s.fleet.increment(agent.kind);
+            }
+        }
+        stats
+    }
+
+    fn generate_report(&self, timestamp: usize) -> StatusReport {
+        let avg_util =
+            self.tiers.iter().map(|t| t.utilization()).sum::<f64>() / self.tiers.len() as f64;
+        let avg_risk = self
+            .tiers
+            .iter()
+            .map(|t| t.effective_failure_rate())
+            .sum::<f64>()
+            / self.tiers.len() as f64;
+
+        let stability_class = if avg_util < 0.6 {
+            "Stable"
+        } else if avg_util < 0.85 {
+            "Degrading"
+        } else {
+            "Critical"
+        }
+        .to_string();
+
+        let mut lifecycle_counts = BTreeMap::new();
+        let mut removed_objects = self.removed_objects;
+        for agent in &self.agents {
+            if agent.lifecycle == LifecycleState::Removed {
+                removed_objects += 1;
+            } else {
+                *lifecycle_counts.entry(agent.lifecycle).or_insert(0) += 1;
+            }
+        }
+
+        let recent_events = count_recent_events(
+            &self.events,
+            timestamp,
+            self.config.reporting.report_interval,
+        );
+        let hazard_events = count_recent_hazards(
+            &self.events,
+            timestamp,
+            self.config.reporting.report_interval,
+        );
+
+        let report_text = self.language.generate_report(
+            &self.tiers,
+            avg_util,
+            avg_risk,
+            hazard_events,
+            &stability_class,
+        );
+
+        StatusReport {
+            timestamp,
+            report_text,
+            ground_truth_utilization: avg_util,
+            ground_truth_risk: avg_risk,
+            agent_count: self.agents.len(),
+            stability_class,
+            recent_events,
+            hazard_events,
+            kind_counts: self
+                .tiers
+                .iter()
+                .fold(KindCounts::default(), |acc, t| acc + t.kind_counts),
+            lifecycle_counts,
+            removed_objects,
+            solar_flux: self.dynamics.solar_flux(timestamp),
+            operators: self.operator_snapshot(),
+            tiers: self.tiers.clone(),
+        }
+    }
+
+    /// Every object's id came from the engine's allocator and no two
+    /// objects share one.
+    fn ids_are_unique(&self) -> bool {
+        let mut seen = HashSet::new();
+        self.agents
+            .iter()
+            .all(|a| self.ids.issued(a.id) && seen.insert(a.id))
+    }
+
+    /// Drop objects that have left orbit, releasing their tier slots.
+    fn cleanup_agents(&mut self) {
+        let tiers = &mut self.tiers;
+        let before = self.agents.len();
+        self.agents.retain(|a| {
+            let removed = a.lifecycle == LifecycleState::Removed;
+            if removed {
+                tiers[a.tier_id].remove_agent(a.kind);
+            }
+            !removed
+        });
+        self.removed_objects += before - self.agents.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+
+    #[test]
+    fn test_interventions_applied_and_logged() {
+        let config = SimulationConfig {
+            time_steps: 50,
+            interventions: serde_json::from_str(
+                r#"[
+                    {"at": 20, "action": "add_tier", "tier": {"name": "LEO-Top", "max_capacity": 100,
+                        "congestion_threshold": 0.8, "failure_base_rate": 0.0005,
+                        "min_altitude_km": 1200.0, "max_altitude_km": 1500.0}},
+                    {"at": 10, "action": "force_deorbit", "tier": "LEO-Low", "fraction": 0.5},
+                    {"at": 30, "action": "set_parameter", "field": "policy.launch_rate", "value": 1.0}
+                ]"#,
+            )
+            .unwrap(),
+            ..SimulationConfig::default()
+        };
+
+        // The deorbit applies at the start of step 10, before any other
+        // intervention, so a run stopped after ten steps sees the same fleet
+        let mut before = SimulationEngine::new(SimulationConfig {
+            time_steps: 10,
+            interventions: Vec::new(),
+            ..config.clone()
+        })
+        .unwrap();
+        before.run();
+        let commandable = before
+            .agents
+            .iter()
+            .filter(|a| a.tier_id == 0 && a.accepts_commands())
+            .count();
+        let deorbited = (0.5 * commandable as f64).round() as usize;
+        assert!(deorbited > 0);
+
+        let results = SimulationEngine::new(config).unwrap().run();
+
+        let applied: Vec<(usize, Option<usize>, usize)> = results
+            .events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Intervention { .. }))
+            .map(|e| (e.timestamp, e.tier_id, e.agent_ids.len()))
+            .collect();
+        assert_eq!(
+            applied,
+            vec![(10, Some(0), deorbited), (20, Some(3), 0), (30, None, 0)]
+        );
+
+        let launches_after = results
+            .events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Launch { .. }) && e.timestamp >= 30)
+            .count();
+        assert_eq!(launches_after, 20);
+
+        let change = results
+            .events
+            .iter()
+            .find(|e| matches!(e.event_type, EventType::PolicyChange { .. }))
+            .unwrap();
+        assert_eq!(change.timestamp, 30);
+        assert!(matches!(
+            &change.event_type,
+            EventType::PolicyChange { field, new_value, .. } if field == "policy.launch_rate" && *new_value == 1.0
+        ));
+    }
+
+    #[test]
+    fn test_band_changes_rebin_objects() {
+        // LEO-High shrinks to leave a gap at 800-1000 km, which a new tier
+        // then fills
+        let config = SimulationConfig {
+            time_steps: 15,
+            interventions: serde_json::from_str(
+                r#"[
+                    {"at": 5, "action": "set_parameter", "field": "capacity_tiers[2].min_altitude_km", "value": 1000.0},
+                    {"at": 10, "action": "add_tier", "tier": {"name": "LEO-Gap", "max_capacity": 200,
+                        "congestion_threshold": 0.8, "failure_base_rate": 0.0005,
+                        "min_altitude_km": 800.0, "max_altitude_km": 1000.0}}
+                ]"#,
+            )
+            .unwrap(),
+            ..SimulationConfig::default()
+        };
+        let mut engine = SimulationEngine::new(config).unwrap();
+        let results = engine.run();
+
+        for tier in &engine.tiers {
+            let members: Vec<&Agent> = engine
+                .agents
+                .iter()
+                .filter(|a| a.tier_id == tier.id)
+                .collect();
+            assert_eq!(tier.current_count, members.len(), "tier {}", tier.name);
+            for agent in members {
+                assert_eq!(tier_for_altitude(&engine.tiers, agent.altitude_km), tier.id);
+            }
+        }
+        assert!(engine.tiers[3].current_count > 0);
+
+        let moved = |t: usize| {
+            results
+                .events
+                .iter()
+                .filter(|e| {
+                    e.timestamp == t
+                        && matches!(
+                            e.event_type,
+                            EventType::Migration {
+                                cause: MigrationCause::BandChange,
+                                ..
+                            }
+                        )
+                })
+                .count()
+        };
+        assert!(moved(5) > 0);
+        assert!(moved(10) > 0);
+    }
+
+    #[test]
+    fn test_rejected_parameter_is_logged_not_fatal() {
+        let config = SimulationConfig::default().with_time_steps(20);
+        let mut engine = SimulationEngine::new(config).unwrap();
+        // Bypasses validation, as a config drifting during the run would
+        engine.interventions = serde_json::from_str(
+            r#"[{"at": 5, "action": "set_parameter", "field": "policy.no_such_field", "value": 1.0}]"#,
+        )
+        .unwrap();
+        let results = engine.run();
+
+        let description = results
+            .events
+            .iter()
+            .find_map(|e| match &e.event_type {
+                EventType::Intervention { description } => Some(description),
+                _ => None,
+            })
+            .unwrap();
+        assert!(description.contains("not applied"), "{}", description);
+        assert!(!results
+            .events
+            .iter()
+            .any(|e| matches!(e.event_type, EventType::PolicyChange { .. })));
+    }
+
+    #[test]
+    fn test_ids_unique_beyond_ten_thousand_objects() {
+        // Congested enough for cascades to shed fragments, with a launch
+        // every step; run() only checks ids in debug builds
+        let mut config = SimulationConfig {
+            time_steps: 20,
+            initial_agents: 12_000,
+            ..SimulationConfig::default()
+        };
+        config.policy.launch_rate = 1.0.into();
+        for tier in &mut config.capacity_tiers {
+            tier.max_capacity = 4_500;
+        }
+        let mut engine = SimulationEngine::new(config).unwrap();
+        let results = engine.run();
+
+        let count = |name: &str| {
+            results
+                .events
+                .iter()
+                .filter(|e| e.event_type.name() == name)
+                .count()
+        };
+        assert!(count("launch") > 0);
+        assert!(count("fragmentation") > 0);
+
+        let mut seen = HashSet::new();
+        for agent in &engine.agents {
+            assert!(seen.insert(agent.id), "id {} is shared", agent.id);
+            assert!(engine.ids.issued(agent.id));
+        }
+        assert!(seen.iter().any(|&id| id >= 12_000));
+    }
+
+    #[test]
+    fn test_operator_tallies_cover_all_objects() {
+        let config = SimulationConfig {
+            time_steps: 200,
+            operators: serde_json::from_str(
+                r#"[{"name": "A", "initial_share": 2.0, "launch_rate": 0.1},
+                    {"name": "B", "launch_rate": 0.05, "risk_appetite": 0.0}]"#,
+            )
+            .unwrap(),
+            ..SimulationConfig::default()
+        };
+        let results = SimulationEngine::new(config).unwrap().run();
+
+        let launches = results
+            .events
+            .iter()
+            .filter(|e| matches!(e.event_type, EventType::Launch { .. }))
+            .count();
+        let operators = &results.operators;
+        assert_eq!(operators.len(), 2);
+        assert_eq!(
+            operators.iter().map(|o| o.launches).sum::<usize>(),
+            launches
+        );
+        assert_eq!(
+            operators.iter().map(|o| o.fleet.total()).sum::<usize>(),
+            results.final_agent_count
+        );
+        assert!(operators[0].fleet.total() > operators[1].fleet.total());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::config::parameters::FragmentationConfig;
+use crate::models::capacity_tier::{tier_for_altitude, CapacityTier};
+use crate::models::event::EventLog;
+use crate::models::object_kind::ObjectKind;
+use crate::models::{agent::Agent, event::Event};
+use crate::utils::ids::IdAllocator;
+use crate::utils::rng::SimulationRng;
+use rand_distr::Normal;
+
+/// Exponent of the cumulative size distribution N(>Lc) ∝ Lc^-1.71.
+const SIZE_EXPONENT: f64 = 1.71;
+
+/// One or more objects breaking up in the same tier and timestep.
+#[derive(Debug, Clone, Default)]
+pub struct Breakup {
+    pub timestamp: usize,
+    pub tier_id: usize,
+    /// Id of the collision or cascading failure that caused the breakup.
+    pub cause: usize,
+    /// Indices into the agent list of the objects that broke up.
+    pub parents: Vec<usize>,
+    /// Mass converted to fragments, in kg, per parent.
+    pub masses: Vec<f64>,
+}
+
+impl Breakup {
+    pub fn new(timestamp: usize, tier_id: usize, cause: usize) -> Self {
+        Self {
+            timestamp,
+            tier_id,
+            cause,
+            ..Self::default()
+        }
+    }
+
+    /// Record a damaged object. A destroyed object fragments completely;
+    /// otherwise only the damaged share of its mass is shed.
+    pub fn add(&mut self, index: usize, agent: &mut Agent, damage: f64) {
+        let mass = if agent.is_alive() {
+            let shed = agent.mass_kg * damage.clamp(0.0, 1.0);
+            agent.mass_kg -= shed;
+            shed
+        } else {
+            agent.mass_kg
+        };
+        self.parents.push(index);
+        self.masses.push(mass);
+    }
+}
+
+/// Turns breakups into debris fragment objects.
+pub struct FragmentationModel {
+    config: FragmentationConfig,
+}
+
+impl FragmentationModel {
+    pub fn new(config: FragmentationConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn set_config(&mut self, config: FragmentationConfig) {
+        self.config = config;
+    }
+
+    /// Number of fragments at or above the minimum characteristic length
+    /// for a breakup of `mass_kg`: N = 0.1 · M^0.75 · Lc^-1.71.
+    pub fn fragment_count(&self, mass_kg: f64) -> usize {
+        let count = 0.1
+            * mass_kg.max(0.0).powf(0.75)
+            * self.config.min_characteristic_length_m.powf(-SIZE_EXPONENT);
+        (count.floor() as usize).min(self.config.max_fragments_per_breakup)
+    }
+
+    /// Create the fragments of `breakup`, placing each in the tier that
+    /// matches its altitude, and log a fragmentation event caused by the
+    /// breakup. Returns the id of that event, if any fragments were created.
+    pub fn fragment(
+        &self,
+        breakup: &Breakup,
+        tiers: &mut [CapacityTier],
+        agents: &mut Vec<Agent>,
+        events: &mut Vec<Event>,
+        ids: &mut IdAllocator,
+        rng: &mut SimulationRng,
+    ) -> Option<usize> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let mut created = 0;
+        for (&parent, &mass) in breakup.parents.iter().zip(&breakup.masses) {
+            let count = self.fragment_count(mass);
+            if count == 0 {
+                continue;
+            }
+            let (altitude, inclination, area, operator_id) = {
+                let p = &agents[parent];
+                (p.altitude_km, p.inclination_deg, p.area_m2, p.operator_id)
+            };
+
+            // Sizes follow the power law between the tracking limit and the parent
+            let min_length = self.config.min_characteristic_length_m;
+            let max_length = area.sqrt().max(min_length);
+            let lengths: Vec<f64> = (0..count)
+                .map(|_| sample_length(min_length, max_length, rng))
+                .collect();
+            // Mass is shared in proportion to fragment volume
+            let volume: f64 = lengths.iter().map(|l| l.powi(3)).sum();
+            let spread = Normal::new(0.0, self.config.altitude_spread_km)
+                .expect("spread validated as non-negative");
+
+            for length in lengths {
+                let fragment_altitude = (altitude + rng.sample(&spread)).max(0.0);
+                let tier_id = tier_for_altitude(tiers, fragment_altitude);
+                let fragment = Agent::new(ids.allocate(), tier_id, false)
+                    .with_kind(ObjectKind::DebrisFragment)
+                    .with_operator(operator_id)
+                    .with_orbit(
+                        fragment_altitude,
+                        inclination,
+                        mass * length.powi(3) / volume,
+                        fragment_area(length),
+                    );
+                tiers[tier_id].add_agent(fragment.kind);
+                agents.push(fragment);
+            }
+            created += count;
+        }
+
+        if created == 0 {
+            return None;
+        }
+        let event = Event::fragmentation(
+            breakup.timestamp,
+            breakup.tier_id,
+            breakup.parents.iter().map(|&i| agents[i].id).collect(),
+            created,
+            breakup.masses.iter().sum(),
+        );
+        Some(events.log(event.caused_by([breakup.cause])))
+    }
+}
+
+/// Inverse-CDF draw from the truncated power law on [min, max].
+fn sample_length(min: f64, max: f64, rng: &mut SimulationRng) -> f64 {
+    let u: f64 = rng.gen_range(0.0..1.0);
+    let tail = (max / min).powf(-SIZE_EXPONENT);
+    min * (1.0 - u * (1.0 - tail)).powf(-1.0 / SIZE_EXPONENT)
+}
+
+/// Average cross-sectional area for a characteristic length, in m².
+fn fragment_area(length: f64) -> f64 {
+    0.556945 * length.powf(2.0047077)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+
+    #[test]
+    fn test_fragment_count_scales_with_mass() {
+        let model = FragmentationModel::new(FragmentationConfig::default());
+        assert_eq!(model.fragment_count(1.0), 0);
+        assert!(model.fragment_count(600.0) > model.fragment_count(100.0));
+        assert_eq!(model.fragment_count(1.0e9), 50);
+    }
+
+    #[test]
+    fn test_fragments_are_placed_by_altitude() {
+        let mut tiers = vec![
+            CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(300.0, 500.0),
+            CapacityTier::new(1, "High".to_string(), 100, 0.7, 0.001)
+                .with_altitude_band(500.0, 800.0),
+        ];
+        let mut ids = IdAllocator::new();
+        let parent = ids.allocate();
+        let mut agents = vec![Agent::new(parent, 0, true).with_orbit(499.0, 53.0, 800.0, 10.0)];
+        tiers[0].add_agent(ObjectKind::ActiveSatellite);
+        agents[0].apply_damage(1.0);
+
+        let mut events = Vec::new();
+        let collision = events.log(Event::collision(3, 0, vec![parent], 1.0));
+        let mut breakup = Breakup::new(3, 0, collision);
+        breakup.add(0, &mut agents[0], 1.0);
+        let mut rng = SimulationRng::new(Some(1));
+        let model = FragmentationModel::new(FragmentationConfig::default());
+        let id = model
+            .fragment(
+                &breakup,
+                &mut tiers,
+                &mut agents,
+                &mut events,
+                &mut ids,
+                &mut rng,
+            )
+            .unwrap();
+        let created = model.fragment_count(800.0);
+
+        assert_eq!(agents.len(), created + 1);
+        assert!(agents[1..]
+            .iter()
+            .all(|a| a.id != parent && ids.issued(a.id)));
+        let debris = tiers[0].kind_counts.debris_fragment + tiers[1].kind_counts.debris_fragment;
+        assert_eq!(debris, created);
+        assert!(
+            tiers[0].kind_counts.debris_fragment > 0 && tiers[1].kind_counts.debris_fragment > 0
+        );
+        let total_mass: f64 = agents[1..].iter().map(|a| a.mass_kg).sum();
+        assert!((total_mass - 800.0).abs() < 1e-6);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[id].agent_ids, vec![parent]);
+        assert_eq!(events[id].parent_ids, vec![collision]);
+        assert!(
+            matches!(events[id].event_type, EventType::Fragmentation { fragments, .. } if fragments == created)
+        );
+    }
+}
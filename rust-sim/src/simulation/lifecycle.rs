@@ -0,0 +1,209 @@
+use crate::config::parameters::LifecycleConfig;
+use crate::models::capacity_tier::CapacityTier;
+use crate::models::event::EventLog;
+use crate::models::lifecycle::LifecycleState;
+use crate::models::object_kind::ObjectKind;
+use crate::models::{agent::Agent, event::Event};
+use std::collections::HashMap;
+
+/// Move `agent` into lifecycle state `to` and log the transition, linked to
+/// the event that caused it if any. A satellite that becomes derelict turns
+/// into a defunct one, keeping the kind counts of its tier in step. Returns
+/// false, changing nothing, if the transition is not legal from the agent's
+/// current state.
+pub fn transition(
+    tiers: &mut [CapacityTier],
+    agent: &mut Agent,
+    to: LifecycleState,
+    cause: Option<usize>,
+    events: &mut Vec<Event>,
+    timestamp: usize,
+) -> bool {
+    let from = agent.lifecycle;
+    if !from.can_transition_to(to) {
+        return false;
+    }
+    if to == LifecycleState::Derelict && agent.kind == ObjectKind::ActiveSatellite {
+        agent.kind = ObjectKind::DefunctSatellite;
+        agent.compliance = false;
+        tiers[agent.tier_id].reclassify(ObjectKind::ActiveSatellite, ObjectKind::DefunctSatellite);
+    }
+    agent.lifecycle = to;
+    agent.lifecycle_since = timestamp;
+    events.log(
+        Event::lifecycle_transition(timestamp, agent.tier_id, agent.id, from, to).caused_by(cause),
+    );
+    true
+}
+
+/// Moves satellites through the timed stages of their life: commissioning,
+/// service, retirement and the deorbit that ends it.
+pub struct LifecycleModel {
+    config: LifecycleConfig,
+    days_per_step: f64,
+}
+
+impl LifecycleModel {
+    pub fn new(config: LifecycleConfig, days_per_step: f64) -> Self {
+        Self {
+            config,
+            days_per_step,
+        }
+    }
+
+    pub fn set_config(&mut self, config: LifecycleConfig, days_per_step: f64) {
+        self.config = config;
+        self.days_per_step = days_per_step;
+    }
+
+    /// Advance every object whose current stage has run its course. Launches
+    /// reach orbit on the step after they lift off.
+    pub fn advance(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        timestamp: usize,
+    ) {
+        let design_life = self.config.design_life_steps(self.days_per_step);
+        for agent in agents.iter_mut() {
+            let days_in_state =
+                timestamp.saturating_sub(agent.lifecycle_since) as f64 * self.days_per_step;
+            let next = match agent.lifecycle {
+                LifecycleState::Launching if agent.lifecycle_since < timestamp => {
+                    LifecycleState::Commissioning
+                }
+                LifecycleState::Commissioning
+                    if days_in_state >= self.config.commissioning_days =>
+                {
+                    LifecycleState::Operational
+                }
+                LifecycleState::Operational if agent.age >= design_life => {
+                    LifecycleState::EndOfLife
+                }
+                LifecycleState::Deorbiting if days_in_state >= self.config.deorbit_days => {
+                    LifecycleState::Removed
+                }
+                _ => continue,
+            };
+            transition(tiers, agent, next, None, events, timestamp);
+        }
+    }
+
+    /// Remove destroyed objects and let failed satellites go derelict.
+    /// `causes` maps agent ids to the last event that damaged them.
+    pub fn settle_failures(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        causes: &HashMap<usize, usize>,
+        events: &mut Vec<Event>,
+        timestamp: usize,
+    ) {
+        for agent in agents.iter_mut() {
+            let cause = causes.get(&agent.id).copied();
+            if agent.lifecycle != LifecycleState::Removed && !agent.is_alive() {
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Removed,
+                    cause,
+                    events,
+                    timestamp,
+                );
+            } else if agent.has_failed() {
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Derelict,
+                    cause,
+                    events,
+                    timestamp,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+
+    #[test]
+    fn test_launched_satellite_lives_and_deorbits() {
+        let mut tiers = vec![CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)];
+        let mut agent = Agent::new(3, 0, true).with_lifecycle(LifecycleState::Launching, 0);
+        tiers[0].add_agent(agent.kind);
+        let config = LifecycleConfig {
+            commissioning_days: 14.0,
+            design_life_years: 1.0,
+            deorbit_days: 7.0,
+        };
+        let model = LifecycleModel::new(config, 7.0);
+        let mut events = Vec::new();
+
+        let mut states = Vec::new();
+        for t in 0..60 {
+            agent.age = t;
+            model.advance(&mut tiers, std::slice::from_mut(&mut agent), &mut events, t);
+            if agent.lifecycle == LifecycleState::EndOfLife {
+                assert!(transition(
+                    &mut tiers,
+                    &mut agent,
+                    LifecycleState::Deorbiting,
+                    None,
+                    &mut events,
+                    t
+                ));
+            }
+            states.push(agent.lifecycle);
+        }
+
+        assert_eq!(states[0], LifecycleState::Launching);
+        assert_eq!(states[1], LifecycleState::Commissioning);
+        assert_eq!(states[3], LifecycleState::Operational);
+        assert_eq!(states[53], LifecycleState::Deorbiting);
+        assert_eq!(states[54], LifecycleState::Removed);
+        assert!(!agent.is_alive());
+        assert!(events
+            .iter()
+            .all(|e| matches!(e.event_type, EventType::LifecycleTransition { .. })));
+        assert_eq!(events.len(), 5);
+
+        // Nothing comes back from removal
+        assert!(!transition(
+            &mut tiers,
+            &mut agent,
+            LifecycleState::Derelict,
+            None,
+            &mut events,
+            60
+        ));
+    }
+
+    #[test]
+    fn test_failed_satellite_goes_derelict() {
+        let mut tiers = vec![CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001)];
+        let mut agents = vec![Agent::new(1, 0, true), Agent::new(2, 0, true)];
+        tiers[0].add_agent(agents[0].kind);
+        tiers[0].add_agent(agents[1].kind);
+        agents[0].apply_damage(0.8);
+        agents[1].apply_damage(1.0);
+
+        let model = LifecycleModel::new(LifecycleConfig::default(), 7.0);
+        let mut events = Vec::new();
+        let collision = events.log(Event::collision(5, 0, vec![2], 1.0));
+        let causes = HashMap::from([(2, collision)]);
+        model.settle_failures(&mut tiers, &mut agents, &causes, &mut events, 5);
+
+        assert_eq!(agents[0].lifecycle, LifecycleState::Derelict);
+        assert_eq!(agents[0].kind, ObjectKind::DefunctSatellite);
+        assert!(!agents[0].compliance && !agents[0].can_maneuver());
+        assert_eq!(tiers[0].kind_counts.defunct_satellite, 1);
+        assert_eq!(agents[1].lifecycle, LifecycleState::Removed);
+        assert_eq!(events.len(), 3);
+        assert!(events[1].parent_ids.is_empty());
+        assert_eq!(events[2].parent_ids, vec![collision]);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::models::agent::Agent;
+use crate::models::capacity_tier::CapacityTier;
+use crate::models::event::{Event, EventLog, MigrationCause};
+
+/// Move `agent` into tier `to_tier`, keeping occupancy of both tiers in step
+/// and logging the move. Does nothing if the agent is already there.
+/// Returns true if the agent changed tier.
+pub fn migrate(
+    tiers: &mut [CapacityTier],
+    agent: &mut Agent,
+    to_tier: usize,
+    cause: MigrationCause,
+    events: &mut Vec<Event>,
+    timestamp: usize,
+) -> bool {
+    let from_tier = agent.tier_id;
+    if from_tier == to_tier {
+        return false;
+    }
+    CapacityTier::transfer(tiers, from_tier, to_tier, agent.kind);
+    agent.tier_id = to_tier;
+    events.log(Event::migration(
+        timestamp, agent.id, from_tier, to_tier, cause,
+    ));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::EventType;
+
+    #[test]
+    fn test_migration_keeps_counts_consistent() {
+        let mut tiers = vec![
+            CapacityTier::new(0, "Low".to_string(), 100, 0.7, 0.001),
+            CapacityTier::new(1, "High".to_string(), 100, 0.7, 0.001),
+        ];
+        let mut agent = Agent::new(4, 0, true);
+        tiers[0].add_agent(agent.kind);
+        let mut events = Vec::new();
+
+        assert!(migrate(
+            &mut tiers,
+            &mut agent,
+            1,
+            MigrationCause::OrbitRaising,
+            &mut events,
+            9
+        ));
+        assert!(!migrate(
+            &mut tiers,
+            &mut agent,
+            1,
+            MigrationCause::OrbitRaising,
+            &mut events,
+            9
+        ));
+        assert_eq!(agent.tier_id, 1);
+        assert_eq!((tiers[0].current_count, tiers[1].current_count), (0, 1));
+        assert_eq!(tiers[1].kind_counts.active_satellite, 1);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0].event_type,
+            EventType::Migration {
+                from_tier: 0,
+                to_tier: 1,
+                cause: MigrationCause::OrbitRaising
+            }
+        ));
+    }
+}
@@ -1,3 +1,7 @@
-pub mod dynamics;
-pub mod engine;
-pub mod policy;
\ No newline at end of file
+pub mod drag;
+pub mod dynamics;
+pub mod engine;
+pub mod fragmentation;
+pub mod lifecycle;
+pub mod migration;
+pub mod policy;
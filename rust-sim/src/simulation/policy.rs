@@ -1,102 +1,292 @@
-use crate::config::parameters::PolicyConfig;
-use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
-use crate::utils::rng::SimulationRng;
-
-pub struct PolicyEngine {
-    config: PolicyConfig,
-    next_agent_id: usize,
-}
-
-impl PolicyEngine {
-    pub fn new(config: PolicyConfig) -> Self {
-        Self {
-            config,
-            next_agent_id: 10000, // Start IDs high to avoid conflicts
-        }
-    }
-
-    pub fn apply(
-        &mut self,
-        tiers: &mut [CapacityTier],
-        agents: &mut Vec<Agent>,
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        // Launch new agents
-        self.launch_agents(tiers, agents, events, timestamp, rng);
-
-        // Apply mitigation
-        self.apply_mitigation(tiers, agents, rng);
-
-        // Deorbit agents in critical tiers
-        self.deorbit_agents(tiers, agents, events, timestamp, rng);
-    }
-
-    fn launch_agents(
-        &mut self,
-        tiers: &mut [CapacityTier],
-        agents: &mut Vec<Agent>,
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        if rng.gen_bool(self.config.launch_rate) {
-            let tier_id = rng.gen_range(0..tiers.len());
-            let compliance = rng.gen_bool(self.config.compliance_rate);
-            
-            let agent = Agent::new(self.next_agent_id, tier_id, compliance);
-            self.next_agent_id += 1;
-            
-            tiers[tier_id].add_agent();
-            events.push(Event::launch(timestamp, tier_id, agent.id));
-            agents.push(agent);
-        }
-    }
-
-    fn apply_mitigation(
-        &self,
-        _tiers: &[CapacityTier],
-        agents: &mut [Agent],
-        rng: &mut SimulationRng,
-    ) {
-        for agent in agents.iter_mut() {
-            if agent.compliance && rng.gen_bool(self.config.mitigation_strength * 0.1) {
-                // Mitigation slightly improves health
-                agent.health = (agent.health + 0.01).min(1.0);
-            }
-        }
-    }
-
-    fn deorbit_agents(
-        &mut self,
-        tiers: &mut [CapacityTier],
-        agents: &mut Vec<Agent>,
-        events: &mut Vec<Event>,
-        timestamp: usize,
-        rng: &mut SimulationRng,
-    ) {
-        let mut to_deorbit = Vec::new();
-
-        for tier in tiers.iter() {
-            if tier.utilization() > self.config.deorbit_threshold {
-                // Force deorbit in critical tiers
-                for agent in agents.iter() {
-                    if agent.tier_id == tier.id && rng.gen_bool(0.3) {
-                        to_deorbit.push(agent.id);
-                        break;
-                    }
-                }
-            }
-        }
-
-        for agent_id in to_deorbit {
-            if let Some(agent) = agents.iter_mut().find(|a| a.id == agent_id) {
-                let tier_id = agent.tier_id;
-                agent.health = 0.0;
-                tiers[tier_id].remove_agent();
-                events.push(Event::deorbit(timestamp, tier_id, agent_id));
-            }
-        }
-    }
-}
\ No newline at end of file
+use crate::config::parameters::{
+    AgentPropertiesConfig, ObjectMixConfig, PolicyConfig, SimulationConfig,
+};
+use crate::models::capacity_tier::next_tier_up;
+use crate::models::event::{ComplianceRule, DeorbitReason, EventLog, MigrationCause};
+use crate::models::lifecycle::LifecycleState;
+use crate::models::object_kind::ObjectKind;
+use crate::models::operator::Operator;
+use crate::models::{agent::Agent, capacity_tier::CapacityTier, event::Event};
+use crate::simulation::lifecycle::transition;
+use crate::simulation::migration::migrate;
+use crate::utils::ids::IdAllocator;
+use crate::utils::rng::SimulationRng;
+
+pub struct PolicyEngine {
+    config: PolicyConfig,
+    agent_properties: AgentPropertiesConfig,
+    object_mix: ObjectMixConfig,
+    operators: Vec<Operator>,
+}
+
+impl PolicyEngine {
+    pub fn new(config: &SimulationConfig) -> Self {
+        Self {
+            config: config.policy.clone(),
+            agent_properties: config.agent_properties.clone(),
+            object_mix: config.object_mix.clone(),
+            operators: Operator::all_from_config(config),
+        }
+    }
+
+    /// Pick up policy and launch settings changed mid-run.
+    pub fn set_config(&mut self, config: &SimulationConfig) {
+        self.config = config.policy.clone();
+        self.agent_properties = config.agent_properties.clone();
+        self.object_mix = config.object_mix.clone();
+        self.operators = Operator::all_from_config(config);
+    }
+
+    pub fn apply(
+        &mut self,
+        tiers: &mut [CapacityTier],
+        agents: &mut Vec<Agent>,
+        events: &mut Vec<Event>,
+        ids: &mut IdAllocator,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        // Operators launch new agents
+        self.launch_agents(tiers, agents, events, ids, timestamp, rng);
+
+        // Apply mitigation
+        self.apply_mitigation(tiers, agents, timestamp, rng);
+
+        // Dispose of satellites that reached the end of their design life
+        self.dispose_retired(tiers, agents, events, timestamp);
+
+        // Relieve congested tiers by raising orbits
+        self.raise_orbits(tiers, agents, events, timestamp, rng);
+
+        // Deorbit agents in critical tiers
+        self.deorbit_agents(tiers, agents, events, timestamp, rng);
+    }
+
+    fn launch_agents(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut Vec<Agent>,
+        events: &mut Vec<Event>,
+        ids: &mut IdAllocator,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        for i in 0..self.operators.len() {
+            let operator = &self.operators[i];
+            if !rng.gen_bool(operator.launch_rate.value_at(timestamp)) {
+                continue;
+            }
+            let tier_id = operator.choose_tier(tiers, rng);
+            let compliance = rng.gen_bool(operator.compliance_rate.value_at(timestamp));
+            let operator_id = operator.id;
+
+            let agent = self
+                .spawn(
+                    &tiers[tier_id],
+                    ObjectKind::ActiveSatellite,
+                    compliance,
+                    ids,
+                    rng,
+                )
+                .with_operator(operator_id)
+                .with_lifecycle(LifecycleState::Launching, timestamp);
+            let mut event = Event::launch(timestamp, tier_id, agent.id, operator_id);
+            tiers[tier_id].add_agent(agent.kind);
+            agents.push(agent);
+
+            // The upper stage may be left in the same shell, which
+            // non-compliant launches do in breach of disposal rules
+            let mut violation = None;
+            if rng.gen_bool(self.object_mix.rocket_body_per_launch) {
+                let stage = self
+                    .spawn(
+                        &tiers[tier_id],
+                        ObjectKind::RocketBody,
+                        compliance,
+                        ids,
+                        rng,
+                    )
+                    .with_operator(operator_id);
+                event.agent_ids.push(stage.id);
+                if !compliance {
+                    let rule = ComplianceRule::UpperStageDisposal;
+                    violation = Some(Event::compliance_violation(
+                        timestamp, tier_id, stage.id, rule,
+                    ));
+                }
+                tiers[tier_id].add_agent(stage.kind);
+                agents.push(stage);
+            }
+
+            events.log(event);
+            if let Some(violation) = violation {
+                events.log(violation);
+            }
+        }
+    }
+
+    fn spawn(
+        &self,
+        tier: &CapacityTier,
+        kind: ObjectKind,
+        compliance: bool,
+        ids: &mut IdAllocator,
+        rng: &mut SimulationRng,
+    ) -> Agent {
+        Agent::spawn(
+            ids.allocate(),
+            tier,
+            kind,
+            compliance,
+            &self.agent_properties,
+            rng,
+        )
+    }
+
+    fn apply_mitigation(
+        &self,
+        _tiers: &[CapacityTier],
+        agents: &mut [Agent],
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        let mitigation_strength = self.config.mitigation_strength.value_at(timestamp);
+        for agent in agents.iter_mut() {
+            // Satellites out of contact cannot be told to mitigate
+            let reachable = agent.kind != ObjectKind::ActiveSatellite || agent.can_communicate();
+            if agent.compliance && reachable && rng.gen_bool(mitigation_strength * 0.1) {
+                // Mitigation slightly improves health
+                agent.health = (agent.health + 0.01).min(1.0);
+            }
+        }
+    }
+
+    /// Compliant operators deorbit retired satellites they still control;
+    /// the rest are abandoned in orbit.
+    fn dispose_retired(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        timestamp: usize,
+    ) {
+        for agent in agents
+            .iter_mut()
+            .filter(|a| a.lifecycle == LifecycleState::EndOfLife)
+        {
+            if agent.compliance && agent.accepts_commands() {
+                let deorbit = events.log(Event::deorbit(
+                    timestamp,
+                    agent.tier_id,
+                    agent.id,
+                    DeorbitReason::Disposal,
+                ));
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Deorbiting,
+                    Some(deorbit),
+                    events,
+                    timestamp,
+                );
+            } else {
+                let violation = (!agent.compliance).then(|| {
+                    let rule = ComplianceRule::PostMissionDisposal;
+                    events.log(Event::compliance_violation(
+                        timestamp,
+                        agent.tier_id,
+                        agent.id,
+                        rule,
+                    ))
+                });
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Derelict,
+                    violation,
+                    events,
+                    timestamp,
+                );
+            }
+        }
+    }
+
+    fn raise_orbits(
+        &self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        let rate = self.config.orbit_raising_rate.value_at(timestamp);
+        // Pair each congested tier with a less utilized tier directly above it
+        let targets: Vec<Option<usize>> = (0..tiers.len())
+            .map(|id| {
+                next_tier_up(tiers, id).filter(|&up| {
+                    tiers[id].is_congested() && tiers[up].utilization() < tiers[id].utilization()
+                })
+            })
+            .collect();
+
+        for agent in agents.iter_mut() {
+            let Some(target) = targets[agent.tier_id] else {
+                continue;
+            };
+            if agent.accepts_commands() && agent.compliance && rng.gen_bool(rate) {
+                agent.altitude_km = tiers[target].sample_altitude(rng);
+                migrate(
+                    tiers,
+                    agent,
+                    target,
+                    MigrationCause::OrbitRaising,
+                    events,
+                    timestamp,
+                );
+            }
+        }
+    }
+
+    fn deorbit_agents(
+        &mut self,
+        tiers: &mut [CapacityTier],
+        agents: &mut [Agent],
+        events: &mut Vec<Event>,
+        timestamp: usize,
+        rng: &mut SimulationRng,
+    ) {
+        let mut to_deorbit = Vec::new();
+        let deorbit_threshold = self.config.deorbit_threshold.value_at(timestamp);
+
+        for tier in tiers.iter() {
+            if tier.utilization() > deorbit_threshold {
+                // Force deorbit in critical tiers
+                for agent in agents.iter() {
+                    if agent.tier_id == tier.id && agent.accepts_commands() && rng.gen_bool(0.3) {
+                        to_deorbit.push(agent.id);
+                        break;
+                    }
+                }
+            }
+        }
+
+        for agent_id in to_deorbit {
+            if let Some(agent) = agents.iter_mut().find(|a| a.id == agent_id) {
+                let deorbit = events.log(Event::deorbit(
+                    timestamp,
+                    agent.tier_id,
+                    agent_id,
+                    DeorbitReason::Congestion,
+                ));
+                transition(
+                    tiers,
+                    agent,
+                    LifecycleState::Deorbiting,
+                    Some(deorbit),
+                    events,
+                    timestamp,
+                );
+            }
+        }
+    }
+}
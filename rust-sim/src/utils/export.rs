@@ -1,34 +1,481 @@
-use crate::simulation::engine::SimulationResults;
-use csv::Writer;
-use std::error::Error;
-
-pub fn export_to_csv(results: &SimulationResults, path: &str) -> Result<(), Box<dyn Error>> {
-    let mut wtr = Writer::from_path(path)?;
-
-    // Write header
-    wtr.write_record(&[
-        "timestamp",
-        "report_text",
-        "ground_truth_utilization",
-        "ground_truth_risk",
-        "agent_count",
-        "stability_class",
-        "recent_events",
-    ])?;
-
-    // Write data
-    for report in &results.reports {
-        wtr.write_record(&[
-            report.timestamp.to_string(),
-            report.report_text.clone(),
-            format!("{:.6}", report.ground_truth_utilization),
-            format!("{:.6}", report.ground_truth_risk),
-            report.agent_count.to_string(),
-            report.stability_class.clone(),
-            report.recent_events.to_string(),
-        ])?;
-    }
-
-    wtr.flush()?;
-    Ok(())
-}
\ No newline at end of file
+use crate::experiments::sensitivity::SensitivityReport;
+use crate::experiments::summary::RunSummary;
+use crate::experiments::sweep::SweepRow;
+use crate::models::lifecycle::LifecycleState;
+use crate::models::operator::OperatorStats;
+use crate::reporting::causality::EventGraph;
+use crate::simulation::engine::{SimulationResults, StatusReport, TierCapacity};
+use csv::Writer;
+use serde::Serialize;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+pub fn export_to_csv(results: &SimulationResults, path: &str) -> Result<(), Box<dyn Error>> {
+    export_reports_to_csv(&results.reports, path)
+}
+
+pub fn export_reports_to_csv(reports: &[StatusReport], path: &str) -> Result<(), Box<dyn Error>> {
+    let mut wtr = Writer::from_path(path)?;
+
+    // Write header, with one column per lifecycle state
+    let mut header = vec![
+        "timestamp",
+        "report_text",
+        "ground_truth_utilization",
+        "ground_truth_risk",
+        "agent_count",
+        "stability_class",
+        "recent_events",
+        "hazard_events",
+        "active_satellites",
+        "defunct_satellites",
+        "rocket_bodies",
+        "debris_fragments",
+        "solar_flux",
+    ];
+    header.extend(LifecycleState::ALL.iter().map(|s| s.name()));
+    wtr.write_record(&header)?;
+
+    // Write data
+    for report in reports {
+        let mut record = vec![
+            report.timestamp.to_string(),
+            report.report_text.clone(),
+            format!("{:.6}", report.ground_truth_utilization),
+            format!("{:.6}", report.ground_truth_risk),
+            report.agent_count.to_string(),
+            report.stability_class.clone(),
+            report.recent_events.to_string(),
+            report.hazard_events.to_string(),
+            report.kind_counts.active_satellite.to_string(),
+            report.kind_counts.defunct_satellite.to_string(),
+            report.kind_counts.rocket_body.to_string(),
+            report.kind_counts.debris_fragment.to_string(),
+            format!("{:.1}", report.solar_flux),
+        ];
+        for state in LifecycleState::ALL {
+            let count = match state {
+                LifecycleState::Removed => report.removed_objects,
+                _ => report.lifecycle_counts.get(&state).copied().unwrap_or(0),
+            };
+            record.push(count.to_string());
+        }
+        wtr.write_record(&record)?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write one row per operator with its fleet by kind and event counts.
+pub fn export_operators_to_csv(
+    operators: &[OperatorStats],
+    path: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut wtr = Writer::from_path(path)?;
+
+    wtr.write_record([
+        "operator_id",
+        "name",
+        "active_satellites",
+        "defunct_satellites",
+        "rocket_bodies",
+        "debris_fragments",
+        "launches",
+        "collisions",
+        "cascading_failures",
+        "fragmentations",
+        "deorbits",
+        "reentries",
+        "migrations",
+        "subsystem_failures",
+        "avoidance_maneuvers",
+        "compliance_violations",
+        "spillovers",
+    ])?;
+
+    for operator in operators {
+        wtr.write_record([
+            operator.operator_id.to_string(),
+            operator.name.clone(),
+            operator.fleet.active_satellite.to_string(),
+            operator.fleet.defunct_satellite.to_string(),
+            operator.fleet.rocket_body.to_string(),
+            operator.fleet.debris_fragment.to_string(),
+            operator.launches.to_string(),
+            operator.collisions.to_string(),
+            operator.cascading_failures.to_string(),
+            operator.fragmentations.to_string(),
+            operator.deorbits.to_string(),
+            operator.reentries.to_string(),
+            operator.migrations.to_string(),
+            operator.subsystem_failures.to_string(),
+            operator.avoidance_maneuvers.to_string(),
+            operator.compliance_violations.to_string(),
+            operator.spillovers.to_string(),
+        ])?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write one row per tier and timestep comparing nominal and effective capacity.
+pub fn export_capacity_to_csv(records: &[TierCapacity], path: &str) -> Result<(), Box<dyn Error>> {
+    let mut wtr = Writer::from_path(path)?;
+
+    wtr.write_record([
+        "timestamp",
+        "tier_id",
+        "tier",
+        "nominal_capacity",
+        "effective_capacity",
+        "capacity_gap",
+        "debris_fragments",
+        "utilization",
+    ])?;
+
+    for record in records {
+        wtr.write_record([
+            record.timestamp.to_string(),
+            record.tier_id.to_string(),
+            record.tier.clone(),
+            record.nominal_capacity.to_string(),
+            format!("{:.2}", record.effective_capacity),
+            format!("{:.2}", record.gap()),
+            record.debris_fragments.to_string(),
+            format!("{:.6}", record.utilization),
+        ])?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write one row per sweep run, keyed by run index, seed and swept parameter values.
+pub fn export_sweep_to_csv(
+    rows: &[SweepRow],
+    fields: &[&str],
+    path: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut wtr = Writer::from_path(path)?;
+
+    let mut header = vec!["run", "seed"];
+    header.extend_from_slice(fields);
+    header.extend_from_slice(&RunSummary::METRICS);
+    wtr.write_record(&header)?;
+
+    for row in rows {
+        let mut record = vec![
+            row.run.index.to_string(),
+            row.run.seed.map(|s| s.to_string()).unwrap_or_default(),
+        ];
+        record.extend(row.param_values());
+        record.extend(row.summary.to_record());
+        wtr.write_record(&record)?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write one row per (metric, parameter) pair of a sensitivity report.
+pub fn export_sensitivity_to_csv(
+    report: &SensitivityReport,
+    path: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut wtr = Writer::from_path(path)?;
+
+    match report {
+        SensitivityReport::Sobol(indices) => {
+            wtr.write_record([
+                "metric",
+                "parameter",
+                "first_order",
+                "first_order_low",
+                "first_order_high",
+                "total_effect",
+                "total_effect_low",
+                "total_effect_high",
+            ])?;
+            for index in indices {
+                wtr.write_record([
+                    index.metric.clone(),
+                    index.parameter.clone(),
+                    format!("{:.6}", index.first_order),
+                    format!("{:.6}", index.first_order_ci.0),
+                    format!("{:.6}", index.first_order_ci.1),
+                    format!("{:.6}", index.total_effect),
+                    format!("{:.6}", index.total_effect_ci.0),
+                    format!("{:.6}", index.total_effect_ci.1),
+                ])?;
+            }
+        }
+        SensitivityReport::Morris(indices) => {
+            wtr.write_record([
+                "metric",
+                "parameter",
+                "mu",
+                "mu_star",
+                "mu_star_low",
+                "mu_star_high",
+                "sigma",
+            ])?;
+            for index in indices {
+                wtr.write_record([
+                    index.metric.clone(),
+                    index.parameter.clone(),
+                    format!("{:.6}", index.mu),
+                    format!("{:.6}", index.mu_star),
+                    format!("{:.6}", index.mu_star_ci.0),
+                    format!("{:.6}", index.mu_star_ci.1),
+                    format!("{:.6}", index.sigma),
+                ])?;
+            }
+        }
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Write the causal chains of an event graph in Graphviz DOT format, with
+/// each event's chain depth in its label. Events that neither caused nor
+/// were caused by another are left out.
+pub fn export_event_graph_to_dot(graph: &EventGraph, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, "digraph events {{")?;
+    writeln!(out, "    node [shape=box];")?;
+    for id in (0..graph.len()).filter(|&id| graph.is_linked(id)) {
+        let event = graph.event(id);
+        writeln!(
+            out,
+            "    e{} [label=\"{}\\nt={} tier={} depth={}\"];",
+            id,
+            event.event_type.name(),
+            event.timestamp,
+            tier_label(event.tier_id),
+            graph.depth(id)
+        )?;
+    }
+    for id in 0..graph.len() {
+        for child in graph.children(id) {
+            writeln!(out, "    e{} -> e{};", id, child)?;
+        }
+    }
+    writeln!(out, "}}")?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Write the causal chains of an event graph as GraphML, with the event
+/// type, timestep, tier, damage and chain depth as node attributes.
+pub fn export_event_graph_to_graphml(graph: &EventGraph, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    for (key, kind) in [
+        ("type", "string"),
+        ("timestamp", "int"),
+        ("tier", "string"),
+        ("damage", "double"),
+        ("depth", "int"),
+    ] {
+        writeln!(
+            out,
+            r#"  <key id="{0}" for="node" attr.name="{0}" attr.type="{1}"/>"#,
+            key, kind
+        )?;
+    }
+    writeln!(out, r#"  <graph id="events" edgedefault="directed">"#)?;
+    for id in (0..graph.len()).filter(|&id| graph.is_linked(id)) {
+        let event = graph.event(id);
+        writeln!(out, r#"    <node id="e{}">"#, id)?;
+        writeln!(
+            out,
+            r#"      <data key="type">{}</data>"#,
+            event.event_type.name()
+        )?;
+        writeln!(
+            out,
+            r#"      <data key="timestamp">{}</data>"#,
+            event.timestamp
+        )?;
+        writeln!(
+            out,
+            r#"      <data key="tier">{}</data>"#,
+            tier_label(event.tier_id)
+        )?;
+        writeln!(
+            out,
+            r#"      <data key="damage">{}</data>"#,
+            event.event_type.damage()
+        )?;
+        writeln!(out, r#"      <data key="depth">{}</data>"#, graph.depth(id))?;
+        writeln!(out, "    </node>")?;
+    }
+    for id in 0..graph.len() {
+        for child in graph.children(id) {
+            writeln!(out, r#"    <edge source="e{}" target="e{}"/>"#, id, child)?;
+        }
+    }
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</graphml>")?;
+    out.flush()?;
+    Ok(())
+}
+
+fn tier_label(tier_id: Option<usize>) -> String {
+    tier_id.map_or_else(|| "-".to_string(), |id| id.to_string())
+}
+
+/// Write one JSON document per line, e.g. an event log.
+pub fn export_to_jsonl<T: Serialize>(items: &[T], path: &str) -> Result<(), Box<dyn Error>> {
+    let mut out = BufWriter::new(File::create(path)?);
+    for item in items {
+        serde_json::to_writer(&mut out, item)?;
+        out.write_all(b"\n")?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+pub fn export_to_json<T: Serialize>(value: &T, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut out = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut out, value)?;
+    out.write_all(b"\n")?;
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event::{Event, EventLog};
+    use std::collections::BTreeSet;
+    use std::fs;
+
+    // The chain from the causality tests: 0 -> 1 -> 2 -> 4 -> 6 <- 5, with
+    // the launch (3) unlinked.
+    fn chain() -> Vec<Event> {
+        let mut events = Vec::new();
+        let warning = events.log(Event::conjunction_warning(0, 0, vec![1, 2], 0.1));
+        let collision = events.log(Event::collision(0, 0, vec![1, 2], 0.4).caused_by([warning]));
+        let breakup =
+            events.log(Event::fragmentation(0, 0, vec![1], 10, 50.0).caused_by([collision]));
+        events.log(Event::launch(1, 0, 2, 0));
+        let cascade =
+            events.log(Event::cascading_failure(1, 0, vec![3, 4], 0.9, 1.1).caused_by([breakup]));
+        let other = events.log(Event::collision(1, 0, vec![5], 0.2));
+        events.log(Event::cascading_failure(2, 0, vec![6], 0.9, 0.5).caused_by([cascade, other]));
+        events
+    }
+
+    fn export_chain(
+        file_name: &str,
+        export: impl Fn(&EventGraph, &str) -> Result<(), Box<dyn Error>>,
+    ) -> String {
+        let events = chain();
+        let graph = EventGraph::new(&events).unwrap();
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), file_name));
+        let path = path.to_str().unwrap();
+        export(&graph, path).unwrap();
+        let text = fs::read_to_string(path).unwrap();
+        fs::remove_file(path).unwrap();
+        text
+    }
+
+    fn expected_edges() -> BTreeSet<(String, String)> {
+        [(0, 1), (1, 2), (2, 4), (4, 6), (5, 6)]
+            .iter()
+            .map(|(a, b)| (format!("e{}", a), format!("e{}", b)))
+            .collect()
+    }
+
+    fn expected_nodes() -> BTreeSet<String> {
+        [0, 1, 2, 4, 5, 6]
+            .iter()
+            .map(|id| format!("e{}", id))
+            .collect()
+    }
+
+    #[test]
+    fn test_event_graph_dot() {
+        let dot = export_chain("events.dot", export_event_graph_to_dot);
+        assert!(dot.starts_with("digraph events {"));
+        assert!(dot.trim_end().ends_with('}'));
+
+        let nodes: BTreeSet<String> = dot
+            .lines()
+            .filter_map(|line| line.trim().split_once(" [label="))
+            .map(|(id, _)| id.to_string())
+            .collect();
+        assert_eq!(nodes, expected_nodes());
+
+        let edges: BTreeSet<(String, String)> = dot
+            .lines()
+            .filter_map(|line| line.trim().trim_end_matches(';').split_once(" -> "))
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect();
+        assert_eq!(edges, expected_edges());
+
+        assert!(dot.contains(r#"e6 [label="cascading_failure\nt=2 tier=0 depth=4"];"#));
+    }
+
+    #[test]
+    fn test_event_graph_graphml() {
+        let xml = export_chain("events.graphml", export_event_graph_to_graphml);
+
+        // Every tag is closed in order, under a single graphml root.
+        let mut open: Vec<&str> = Vec::new();
+        let mut roots = 0;
+        let mut nodes = BTreeSet::new();
+        let mut edges = BTreeSet::new();
+        let mut rest = xml.as_str();
+        while let Some(start) = rest.find('<') {
+            let end = start + rest[start..].find('>').expect("unterminated tag");
+            let tag = &rest[start + 1..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with('?') {
+                assert!(tag.ends_with('?'));
+                continue;
+            }
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(name));
+                continue;
+            }
+            let name = tag.split_whitespace().next().unwrap().trim_end_matches('/');
+            let attribute = |key: &str| {
+                let (_, value) = tag.split_once(&format!(" {}=\"", key))?;
+                value.split_once('"').map(|(v, _)| v.to_string())
+            };
+            match name {
+                "node" => {
+                    nodes.insert(attribute("id").unwrap());
+                }
+                "edge" => {
+                    edges.insert((attribute("source").unwrap(), attribute("target").unwrap()));
+                }
+                _ => {}
+            }
+            if open.is_empty() {
+                assert_eq!(name, "graphml");
+                roots += 1;
+            }
+            if !tag.ends_with('/') {
+                open.push(name);
+            }
+        }
+        assert!(open.is_empty(), "unclosed tags {:?}", open);
+        assert_eq!(roots, 1);
+        assert!(!rest.contains('>') && rest.trim().is_empty());
+
+        assert_eq!(nodes, expected_nodes());
+        assert_eq!(edges, expected_edges());
+        assert!(xml.contains(r#"<data key="depth">4</data>"#));
+    }
+}
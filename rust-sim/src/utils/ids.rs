@@ -0,0 +1,23 @@
+/// Hands out object ids that are unique across a run. Ids are never reused,
+/// so an id in the event log names one object even after it has left orbit.
+#[derive(Debug, Clone, Default)]
+pub struct IdAllocator {
+    next: usize,
+}
+
+impl IdAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allocate(&mut self) -> usize {
+        let id = self.next;
+        self.next += 1;
+        id
+    }
+
+    /// Whether `id` was handed out by this allocator.
+    pub fn issued(&self, id: usize) -> bool {
+        id < self.next
+    }
+}
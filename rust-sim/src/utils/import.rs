@@ -0,0 +1,28 @@
+use serde::de::DeserializeOwned;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// Read a file written by `export_to_jsonl`, skipping blank lines.
+pub fn import_jsonl<T: DeserializeOwned>(path: &str) -> Result<Vec<T>, Box<dyn Error>> {
+    let reader =
+        BufReader::new(File::open(path).map_err(|e| format!("failed to open {}: {}", path, e))?);
+    let mut items = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let item =
+            serde_json::from_str(&line).map_err(|e| format!("{} line {}: {}", path, i + 1, e))?;
+        items.push(item);
+    }
+    Ok(items)
+}
+
+pub fn import_json<T: DeserializeOwned>(path: &str) -> Result<T, Box<dyn Error>> {
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {}", path, e))?;
+    let value = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("failed to parse {}: {}", path, e))?;
+    Ok(value)
+}
@@ -1,2 +1,4 @@
-pub mod export;
-pub mod rng;
\ No newline at end of file
+pub mod export;
+pub mod ids;
+pub mod import;
+pub mod rng;
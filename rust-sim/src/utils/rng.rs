@@ -1,29 +1,43 @@
-use rand::{Rng, SeedableRng};
-use rand::rngs::StdRng;
-
-pub struct SimulationRng {
-    rng: StdRng,
-}
-
-impl SimulationRng {
-    pub fn new(seed: Option<u64>) -> Self {
-        let rng = match seed {
-            Some(s) => StdRng::seed_from_u64(s),
-            None => StdRng::from_entropy(),
-        };
-        
-        Self { rng }
-    }
-
-    pub fn gen_bool(&mut self, p: f64) -> bool {
-        self.rng.gen_bool(p)
-    }
-
-    pub fn gen_range<T, R>(&mut self, range: R) -> T
-    where
-        T: rand::distributions::uniform::SampleUniform,
-        R: rand::distributions::uniform::SampleRange<T>,
-    {
-        self.rng.gen_range(range)
-    }
-}
\ No newline at end of file
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+pub struct SimulationRng {
+    rng: StdRng,
+}
+
+impl SimulationRng {
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(s) => StdRng::seed_from_u64(s),
+            None => StdRng::from_entropy(),
+        };
+
+        Self { rng }
+    }
+
+    pub fn gen_bool(&mut self, p: f64) -> bool {
+        self.rng.gen_bool(p)
+    }
+
+    pub fn gen_range<T, R>(&mut self, range: R) -> T
+    where
+        T: rand::distributions::uniform::SampleUniform,
+        R: rand::distributions::uniform::SampleRange<T>,
+    {
+        self.rng.gen_range(range)
+    }
+
+    pub fn sample<T, D: Distribution<T>>(&mut self, distribution: &D) -> T {
+        distribution.sample(&mut self.rng)
+    }
+
+    /// Up to `amount` distinct items drawn uniformly from `items`.
+    pub fn choose_multiple<T: Clone>(&mut self, items: &[T], amount: usize) -> Vec<T> {
+        items
+            .choose_multiple(&mut self.rng, amount)
+            .cloned()
+            .collect()
+    }
+}